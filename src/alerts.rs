@@ -0,0 +1,164 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::app::file_cache::cache::get_alert_settings;
+
+use crate::structs::TLPass;
+
+/// Seconds an on-screen alert banner stays up
+pub const BANNER_DURATION: i64 = 10;
+
+/// Largest clock jump (seconds) between checks that still fires the alerts passed over, beyond which it is treated as a scrub
+pub const MAX_CHECK_GAP: i64 = 600;
+
+/// AOS times (seconds) closer than this are taken to be the same pass when passes are re-propagated
+const SAME_PASS_TOLERANCE: i64 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PassEvent {
+    PreAos,
+    Aos,
+    Tme,
+    Los,
+}
+
+/// Alert configuration, read from `alerts.json` in the data directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertSettings {
+    pub enabled: bool,
+    pub lead_minutes: i64,
+    pub pre_aos: bool,
+    pub aos: bool,
+    pub los: bool,
+    pub min_elevation: f64,
+    pub bell: bool,
+    pub banner: bool,
+    pub desktop: bool,
+}
+
+impl Default for AlertSettings {
+    fn default() -> Self {
+        AlertSettings {
+            enabled: true,
+            lead_minutes: 5,
+            pre_aos: true,
+            aos: true,
+            los: true,
+            min_elevation: 0.0,
+            bell: true,
+            banner: true,
+            desktop: false,
+        }
+    }
+}
+
+impl AlertSettings {
+    /// Time of each enabled alert for the pass, or nothing if the pass is below the elevation threshold
+    pub fn events(&self, pass: &TLPass) -> Vec<(PassEvent, DateTime<Utc>)> {
+        if pass.pass.get_max_elevation() < self.min_elevation {
+            return vec![];
+        }
+        let aos = pass.pass.get_aos_datetime();
+        [
+            (
+                self.pre_aos,
+                PassEvent::PreAos,
+                aos - TimeDelta::minutes(self.lead_minutes),
+            ),
+            (self.aos, PassEvent::Aos, aos),
+            (self.los, PassEvent::Los, pass.pass.get_los_datetime()),
+        ]
+        .into_iter()
+        .filter(|x| x.0)
+        .map(|x| (x.1, x.2))
+        .collect()
+    }
+}
+
+/// Which alerts and hooks have already been raised for a pass
+#[derive(Debug, Clone, Default)]
+pub struct AlertState {
+    pub alerts: Vec<PassEvent>,
+    pub hooks: Vec<usize>,
+}
+
+/// Copies the alert state of passes in `old` onto the matching re-propagated passes in `new`
+pub fn carry_alert_state(old: &[TLPass], new: &mut [TLPass]) {
+    for i in new.iter_mut() {
+        if let Some(x) = old.iter().find(|x| {
+            x.station.name == i.station.name
+                && (x.pass.get_aos() - i.pass.get_aos()).abs() < SAME_PASS_TOLERANCE
+        }) {
+            i.alerts = x.alerts.clone();
+        }
+    }
+}
+
+pub struct Banner {
+    pub text: String,
+    pub expires: DateTime<Utc>,
+}
+
+pub struct Alerts {
+    pub settings: AlertSettings,
+    pub last_check: DateTime<Utc>,
+    pub banner: Option<Banner>,
+}
+
+impl Default for Alerts {
+    fn default() -> Self {
+        let settings = get_alert_settings().unwrap_or_else(|x| {
+            warn!("Unable to load alert settings: {}", x);
+            AlertSettings::default()
+        });
+        Alerts {
+            settings,
+            last_check: Utc::now(),
+            banner: None,
+        }
+    }
+}
+
+/// Rings the terminal bell
+#[cfg(not(target_arch = "wasm32"))]
+pub fn ring_bell() {
+    use std::io::Write;
+
+    print!("\x07");
+    let _ = std::io::stdout().flush();
+}
+
+/// Shows a desktop notification through the freedesktop notification service on the session bus, without blocking
+#[cfg(not(target_arch = "wasm32"))]
+pub fn desktop_notify(summary: &str, body: &str) {
+    use std::process::Command;
+
+    let quote = |x: &str| format!("'{}'", x.replace('\\', "\\\\").replace('\'', "\\'"));
+    let args = vec![
+        "call".to_string(),
+        "--session".to_string(),
+        "--dest=org.freedesktop.Notifications".to_string(),
+        "--object-path=/org/freedesktop/Notifications".to_string(),
+        "--method=org.freedesktop.Notifications.Notify".to_string(),
+        quote(env!("CARGO_PKG_NAME")),
+        "0".to_string(),
+        quote(""),
+        quote(summary),
+        quote(body),
+        "[]".to_string(),
+        "{}".to_string(),
+        "5000".to_string(),
+    ];
+    std::thread::spawn(move || match Command::new("gdbus").args(&args).output() {
+        Ok(x) if !x.status.success() => {
+            warn!(
+                "Desktop notification failed: {}",
+                String::from_utf8_lossy(&x.stderr)
+            )
+        }
+        Err(x) => warn!("Unable to send desktop notification: {}", x),
+        _ => {}
+    });
+}
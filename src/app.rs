@@ -1,16 +1,38 @@
 use addsat_message::parse_addsat_msg;
-use chrono::{Days, Utc};
+use alert_message::parse_alert_msg;
+use chrono::Days;
+use clock_message::parse_clock_msg;
+use conjunction_message::parse_conjunction_msg;
 use file_cache::cache::cache_gs;
 use gs_message::parse_gsconfig_msg;
+use history_message::{parse_history_msg, record_history};
+use link_message::parse_link_msg;
+use map_message::parse_map_msg;
+use pass_message::{parse_pass_detail_msg, parse_pass_list_msg};
 use satlist_message::parse_satlist_msg;
+use schedule_message::parse_schedule_msg;
 use sky_track::{GroundStation, find_passes_datetime};
 use std::cell::Cell;
 use tracing::{debug, info};
 mod addsat_message;
+mod alert_message;
+mod clock_message;
+mod conjunction_message;
 mod gs_message;
+mod history_message;
 pub mod key_handle;
+mod link_message;
+mod map_message;
+pub mod mouse_handle;
+mod pass_message;
 mod satlist_message;
-use crate::structs::{AppState, CurrentMsg, Message, Model, TLPass};
+mod schedule_message;
+use crate::alerts::{AlertState, carry_alert_state};
+use crate::ground_track::sample_step;
+use crate::keymap::{HelpView, KeyContext, help_rows};
+use crate::pass_plan::sun_approach;
+use crate::projection::MapProjection;
+use crate::structs::{AppState, CurrentMsg, ListMovement, Message, Model, TLPass};
 pub mod file_cache;
 
 #[cfg(target_arch = "wasm32")]
@@ -20,9 +42,11 @@ use std::sync::mpsc::Sender;
 pub fn update(model: &mut Model, message: Message, tx: Sender<Message>) {
     use file_cache::cache::{cache_sup_data, cache_tle, get_sat_cache};
     use sky_track::Satellite;
+    use tracing::warn;
     use wasm_bindgen::{JsCast, prelude::Closure};
 
     use crate::structs::SatSelection;
+    use crate::tle::validate_tle;
 
     let message: Cell<Option<Message>> = Cell::new(Some(message));
     while let Some(msg) = message.take() {
@@ -67,6 +91,82 @@ pub fn update(model: &mut Model, message: Message, tx: Sender<Message>) {
             Message::GSConfigMsg(gsconfig_msg) => {
                 message.set(parse_gsconfig_msg(model, gsconfig_msg))
             }
+            Message::ClockMsg(clock_msg) => message.set(parse_clock_msg(model, clock_msg)),
+            Message::MapMsg(map_msg) => message.set(parse_map_msg(model, map_msg)),
+            Message::PassListMsg(list_movement) => {
+                message.set(parse_pass_list_msg(model, list_movement))
+            }
+            Message::PassDetailMsg(pass_detail_msg) => {
+                message.set(parse_pass_detail_msg(model, pass_detail_msg))
+            }
+            Message::AlertMsg(alert_msg) => message.set(parse_alert_msg(model, alert_msg)),
+            Message::ToggleEventLog => {
+                if model.current_state != AppState::EventLog {
+                    model.current_state = AppState::EventLog;
+                } else {
+                    model.current_state = AppState::Base;
+                }
+            }
+            Message::EventLogMsg(list_movement) => match list_movement {
+                ListMovement::Up => model.event_log_state.scroll_up_by(1),
+                ListMovement::Down => model.event_log_state.scroll_down_by(1),
+                _ => {}
+            },
+            Message::ToggleHistory => {
+                if model.current_state != AppState::History {
+                    model.current_state = AppState::History;
+                } else {
+                    model.history.editing = false;
+                    model.current_state = AppState::Base;
+                }
+            }
+            Message::HistoryMsg(history_msg) => message.set(parse_history_msg(model, history_msg)),
+            Message::LinkMsg(link_msg) => message.set(parse_link_msg(model, link_msg)),
+            Message::ToggleConjunctions => {
+                if model.current_state != AppState::Conjunctions {
+                    info!("Opening conjunction screening");
+                    model.current_state = AppState::Conjunctions;
+                } else {
+                    model.current_state = AppState::Base;
+                }
+            }
+            Message::ConjunctionMsg(conjunction_msg) => {
+                message.set(parse_conjunction_msg(model, conjunction_msg))
+            }
+            Message::ToggleHelp => {
+                if model.current_state != AppState::Help {
+                    model.help = HelpView {
+                        return_state: model.current_state,
+                        context: KeyContext::of(model),
+                        scroll: 0,
+                    };
+                    model.current_state = AppState::Help;
+                } else {
+                    model.current_state = model.help.return_state;
+                }
+            }
+            Message::HelpMsg(list_movement) => {
+                let rows = help_rows(&model.keymap, model.help.context).len();
+                match list_movement {
+                    ListMovement::Up => model.help.scroll = model.help.scroll.saturating_sub(1),
+                    ListMovement::Down => {
+                        model.help.scroll = (model.help.scroll + 1).min(rows.saturating_sub(1))
+                    }
+                    _ => {}
+                }
+            }
+            Message::ToggleElements => model.show_elements = !model.show_elements,
+            Message::ToggleSchedule => {
+                if model.current_state != AppState::Schedule {
+                    info!("Opening pass schedule");
+                    model.current_state = AppState::Schedule;
+                } else {
+                    model.current_state = AppState::Base;
+                }
+            }
+            Message::ScheduleMsg(schedule_msg) => {
+                message.set(parse_schedule_msg(model, schedule_msg))
+            }
             Message::PropagatePasses => {
                 {
                     let current_stations: Vec<GroundStation> = model
@@ -76,22 +176,36 @@ pub fn update(model: &mut Model, message: Message, tx: Sender<Message>) {
                         .filter(|x| x.active)
                         .map(|x| x.station.clone())
                         .collect();
-
+                    let now = model.clock.now();
+                    record_history(model, now);
+                    model.pass_window_start = now;
                     let mut passes: Vec<TLPass> = vec![];
                     if current_stations.len() == 0 || model.current_satellite.is_none() {
                     } else {
+                        let norad_id = model
+                            .current_satellite
+                            .as_ref()
+                            .map(|x| x.satellite.get_norad_id())
+                            .unwrap_or_default();
                         for i in current_stations {
                             passes.append(
                                 &mut find_passes_datetime(
                                     &model.current_satellite.as_ref().unwrap().satellite,
                                     &i,
-                                    &Utc::now(),
-                                    &Utc::now().checked_add_days(Days::new(3)).unwrap(),
+                                    &now,
+                                    &now.checked_add_days(Days::new(3)).unwrap(),
                                 )
                                 .iter()
                                 .map(|x| TLPass {
                                     pass: x.clone(),
                                     station: i.clone(),
+                                    norad_id,
+                                    alerts: AlertState::default(),
+                                    sun: sun_approach(
+                                        &model.current_satellite.as_ref().unwrap().satellite,
+                                        &i,
+                                        x,
+                                    ),
                                 })
                                 .collect(),
                             ) //make configurable
@@ -100,6 +214,7 @@ pub fn update(model: &mut Model, message: Message, tx: Sender<Message>) {
                             debug!("{:?}", i)
                         }
                         passes.sort_by(|a, b| a.pass.get_aos().cmp(&b.pass.get_aos()));
+                        carry_alert_state(&model.upcoming_passes, &mut passes);
                         info!("Updated Passes!");
                         model.upcoming_passes = passes;
                         let tx_clone = tx.clone();
@@ -112,7 +227,7 @@ pub fn update(model: &mut Model, message: Message, tx: Sender<Message>) {
                             .set_timeout_with_callback_and_timeout_and_arguments_0(
                                 callback.as_ref().unchecked_ref(),
                                 (model.upcoming_passes[0].pass.get_los() + 30
-                                    - Utc::now().timestamp())
+                                    - model.clock.now().timestamp())
                                     as i32,
                             );
                     }
@@ -120,6 +235,21 @@ pub fn update(model: &mut Model, message: Message, tx: Sender<Message>) {
             }
             Message::UpdatePass => message.set(Some(Message::PropagatePasses)),
             Message::TLEResponse(id, y) => {
+                let errors = validate_tle(&y);
+                if !errors.is_empty() {
+                    warn!("Celestrak returned an invalid TLE for {}: {:?}", id, errors);
+                    message.set(Some(Message::FetchError(format!(
+                        "Invalid TLE for {}: {}",
+                        id,
+                        errors
+                            .iter()
+                            .map(|x| x.to_string())
+                            .collect::<Vec<String>>()
+                            .join("; ")
+                    ))));
+                    model.sat_config.add_sat.errors = errors;
+                    continue;
+                }
                 let satellite = Satellite::new_from_tle(&y);
                 let cache_result = cache_tle(satellite);
                 if cache_result.is_err() {
@@ -169,6 +299,7 @@ pub fn update(model: &mut Model, message: Message, tx: Sender<Message>) {
             }
         }
     }
+    refresh_map(model);
 }
 #[cfg(not(target_arch = "wasm32"))]
 pub fn update(model: &mut Model, message: Message) {
@@ -212,6 +343,82 @@ pub fn update(model: &mut Model, message: Message) {
             Message::GSConfigMsg(gsconfig_msg) => {
                 message.set(parse_gsconfig_msg(model, gsconfig_msg))
             }
+            Message::ClockMsg(clock_msg) => message.set(parse_clock_msg(model, clock_msg)),
+            Message::MapMsg(map_msg) => message.set(parse_map_msg(model, map_msg)),
+            Message::PassListMsg(list_movement) => {
+                message.set(parse_pass_list_msg(model, list_movement))
+            }
+            Message::PassDetailMsg(pass_detail_msg) => {
+                message.set(parse_pass_detail_msg(model, pass_detail_msg))
+            }
+            Message::AlertMsg(alert_msg) => message.set(parse_alert_msg(model, alert_msg)),
+            Message::ToggleEventLog => {
+                if model.current_state != AppState::EventLog {
+                    model.current_state = AppState::EventLog;
+                } else {
+                    model.current_state = AppState::Base;
+                }
+            }
+            Message::EventLogMsg(list_movement) => match list_movement {
+                ListMovement::Up => model.event_log_state.scroll_up_by(1),
+                ListMovement::Down => model.event_log_state.scroll_down_by(1),
+                _ => {}
+            },
+            Message::ToggleHistory => {
+                if model.current_state != AppState::History {
+                    model.current_state = AppState::History;
+                } else {
+                    model.history.editing = false;
+                    model.current_state = AppState::Base;
+                }
+            }
+            Message::HistoryMsg(history_msg) => message.set(parse_history_msg(model, history_msg)),
+            Message::LinkMsg(link_msg) => message.set(parse_link_msg(model, link_msg)),
+            Message::ToggleConjunctions => {
+                if model.current_state != AppState::Conjunctions {
+                    info!("Opening conjunction screening");
+                    model.current_state = AppState::Conjunctions;
+                } else {
+                    model.current_state = AppState::Base;
+                }
+            }
+            Message::ConjunctionMsg(conjunction_msg) => {
+                message.set(parse_conjunction_msg(model, conjunction_msg))
+            }
+            Message::ToggleHelp => {
+                if model.current_state != AppState::Help {
+                    model.help = HelpView {
+                        return_state: model.current_state,
+                        context: KeyContext::of(model),
+                        scroll: 0,
+                    };
+                    model.current_state = AppState::Help;
+                } else {
+                    model.current_state = model.help.return_state;
+                }
+            }
+            Message::HelpMsg(list_movement) => {
+                let rows = help_rows(&model.keymap, model.help.context).len();
+                match list_movement {
+                    ListMovement::Up => model.help.scroll = model.help.scroll.saturating_sub(1),
+                    ListMovement::Down => {
+                        model.help.scroll = (model.help.scroll + 1).min(rows.saturating_sub(1))
+                    }
+                    _ => {}
+                }
+            }
+            Message::ToggleElements => model.show_elements = !model.show_elements,
+            Message::ToggleSchedule => {
+                if model.current_state != AppState::Schedule {
+                    info!("Opening pass schedule");
+                    model.current_state = AppState::Schedule;
+                } else {
+                    model.current_state = AppState::Base;
+                }
+            }
+            Message::ScheduleMsg(schedule_msg) => {
+                message.set(parse_schedule_msg(model, schedule_msg))
+            }
             Message::PropagatePasses => {
                 {
                     let current_stations: Vec<GroundStation> = model
@@ -221,21 +428,36 @@ pub fn update(model: &mut Model, message: Message) {
                         .filter(|x| x.active)
                         .map(|x| x.station.clone())
                         .collect();
+                    let now = model.clock.now();
+                    record_history(model, now);
+                    model.pass_window_start = now;
                     let mut passes: Vec<TLPass> = vec![];
                     if current_stations.is_empty() || model.current_satellite.is_none() {
                     } else {
+                        let norad_id = model
+                            .current_satellite
+                            .as_ref()
+                            .map(|x| x.satellite.get_norad_id())
+                            .unwrap_or_default();
                         for i in current_stations {
                             passes.append(
                                 &mut find_passes_datetime(
                                     &model.current_satellite.as_ref().unwrap().satellite,
                                     &i,
-                                    &Utc::now(),
-                                    &Utc::now().checked_add_days(Days::new(3)).unwrap(),
+                                    &now,
+                                    &now.checked_add_days(Days::new(3)).unwrap(),
                                 )
                                 .iter()
                                 .map(|x| TLPass {
                                     pass: *x,
                                     station: i.clone(),
+                                    norad_id,
+                                    alerts: AlertState::default(),
+                                    sun: sun_approach(
+                                        &model.current_satellite.as_ref().unwrap().satellite,
+                                        &i,
+                                        x,
+                                    ),
                                 })
                                 .collect(),
                             ) //make configurable
@@ -245,10 +467,29 @@ pub fn update(model: &mut Model, message: Message) {
                         }
                     }
                     passes.sort_by(|a, b| a.pass.get_aos().cmp(&b.pass.get_aos()));
+                    carry_alert_state(&model.upcoming_passes, &mut passes);
                     info!("Updated Passes!");
                     model.upcoming_passes = passes;
                 }
             }
         }
     }
+    refresh_map(model);
+}
+
+/// Brings the cached coastlines and ground track up to date for the next frame, sampled for the map as last drawn
+fn refresh_map(model: &mut Model) {
+    let map = model.layout.borrow().map;
+    let projection = MapProjection::new(model, map);
+    model.coastlines.update(&projection);
+    let step = sample_step(model.sub_point_range, map.width, model.map.zoom);
+    if let Some(satellite) = model.current_satellite.as_ref() {
+        model.ground_track.update(
+            &satellite.satellite,
+            model.clock.now().timestamp(),
+            model.sub_point_range,
+            step,
+            &projection,
+        );
+    }
 }
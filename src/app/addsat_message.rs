@@ -3,6 +3,7 @@ use crate::app::file_cache::cache::{cache_tle, get_sup_data_spacetrack, get_tle_
 #[cfg(not(target_arch = "wasm32"))]
 use crate::structs::TLSatellite;
 use crate::structs::{AddSatMsg, AddSatSel, AppState, CurrentMsg, Message, MetaData, Model};
+use crate::tle::validate_tle;
 #[cfg(target_arch = "wasm32")]
 use ehttp::Request;
 #[cfg(not(target_arch = "wasm32"))]
@@ -25,6 +26,7 @@ pub fn parse_addsat_msg(model: &mut Model, add_sat_msg: AddSatMsg) -> Option<Mes
         AddSatMsg::ToggleEditing => {
             model.sat_config.add_sat.editing = !model.sat_config.add_sat.editing;
             model.sat_config.add_sat.text = "".to_string();
+            model.sat_config.add_sat.errors.clear();
             None
         }
         #[cfg(not(target_arch = "wasm32"))]
@@ -35,6 +37,9 @@ pub fn parse_addsat_msg(model: &mut Model, add_sat_msg: AddSatMsg) -> Option<Mes
                 if let Ok(x) = model.sat_config.add_sat.text.parse::<u64>() {
                     if let Ok(y) = get_tle_spacetrack(x) {
                         info!("Got TLE from spacetrack:{}", y.as_str());
+                        if reject_tle(model, &y, "Celestrak returned an invalid TLE") {
+                            return None;
+                        }
                         let sup_data = get_sup_data_spacetrack(&x.to_string());
                         if let Ok(z) = sup_data {
                             info!("Got Sup data from spacetrack:{:?}", z);
@@ -60,6 +65,10 @@ pub fn parse_addsat_msg(model: &mut Model, add_sat_msg: AddSatMsg) -> Option<Mes
                     return None;
                 }
             } else {
+                let text = model.sat_config.add_sat.text.clone();
+                if reject_tle(model, &text, "Invalid TLE") {
+                    return None;
+                }
                 satellite = Satellite::new_from_tle(&model.sat_config.add_sat.text);
                 let rs_metadata = get_sup_data_spacetrack(&satellite.get_norad_id().to_string());
                 if rs_metadata.is_err() {
@@ -123,16 +132,11 @@ pub fn parse_addsat_msg(model: &mut Model, add_sat_msg: AddSatMsg) -> Option<Mes
         AddSatMsg::PasteTLE => {
             if model.sat_config.add_sat.selected == AddSatSel::TLEBox {
                 let x = model.sat_config.clipboard.get_text();
-                if x.lines().count() <= 3
-                    && x.lines().count() > 0
-                    && !x.lines().any(|y| y.len() < 70)
-                {
-                    model.sat_config.add_sat.editing = true;
-                    model.sat_config.add_sat.text = x;
+                model.sat_config.add_sat.editing = true;
+                model.sat_config.add_sat.text = x.trim().to_string();
+                if !reject_tle(model, x.trim(), "Pasted TLE is invalid") {
                     model.sat_config.current_message = CurrentMsg::message("Pasted TLE");
                 }
-
-                model.sat_config.current_message = CurrentMsg::error("Unable to paste TLE");
                 None
             } else {
                 None
@@ -150,6 +154,7 @@ pub fn parse_addsat_msg(
         AddSatMsg::ToggleEditing => {
             model.sat_config.add_sat.editing = !model.sat_config.add_sat.editing;
             model.sat_config.add_sat.text = "".to_string();
+            model.sat_config.add_sat.errors.clear();
             None
         }
         AddSatMsg::StopEditing => {
@@ -249,6 +254,10 @@ pub fn parse_addsat_msg(
                     return None;
                 }
             } else {
+                let text = model.sat_config.add_sat.text.clone();
+                if reject_tle(model, &text, "Invalid TLE") {
+                    return None;
+                }
                 let satellite = Satellite::new_from_tle(&model.sat_config.add_sat.text);
                 tx_channel
                     .send(Message::TLEResponse(
@@ -337,15 +346,11 @@ pub fn parse_addsat_msg(
         AddSatMsg::PasteTLE => {
             if model.sat_config.add_sat.selected == AddSatSel::TLEBox {
                 let x = model.sat_config.clipboard.get_text();
-                if x.lines().count() <= 3 && x.lines().count() > 0 {
-                    if x.lines().find(|y| y.len() < 70).is_none() {
-                        model.sat_config.add_sat.editing = true;
-                        model.sat_config.add_sat.text = x;
-                        model.sat_config.current_message = CurrentMsg::message("Pasted TLE");
-                    }
+                model.sat_config.add_sat.editing = true;
+                model.sat_config.add_sat.text = x.trim().to_string();
+                if !reject_tle(model, x.trim(), "Pasted TLE is invalid") {
+                    model.sat_config.current_message = CurrentMsg::message("Pasted TLE");
                 }
-
-                model.sat_config.current_message = CurrentMsg::error("Unable to paste TLE");
                 None
             } else {
                 None
@@ -353,3 +358,16 @@ pub fn parse_addsat_msg(
         }
     }
 }
+
+/// Checks the TLE, showing each problem in the popup. Returns whether it was rejected.
+fn reject_tle(model: &mut Model, tle: &str, summary: &str) -> bool {
+    let errors = validate_tle(tle);
+    let rejected = !errors.is_empty();
+    if rejected {
+        warn!("{}: {:?}", summary, errors);
+        model.sat_config.current_message =
+            CurrentMsg::error(&format!("{}: {} error(s)", summary, errors.len()));
+    }
+    model.sat_config.add_sat.errors = errors;
+    rejected
+}
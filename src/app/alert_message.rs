@@ -0,0 +1,202 @@
+use chrono::{TimeDelta, Utc};
+use tracing::info;
+
+use crate::alerts::{BANNER_DURATION, Banner, MAX_CHECK_GAP, PassEvent};
+use crate::hooks::LogEntry;
+use crate::structs::{AlertMsg, Message, Model, TLPass};
+
+pub fn parse_alert_msg(model: &mut Model, alert_msg: AlertMsg) -> Option<Message> {
+    match alert_msg {
+        AlertMsg::Check => {
+            check_alerts(model);
+            None
+        }
+        AlertMsg::Toggle => {
+            model.alerts.settings.enabled = !model.alerts.settings.enabled;
+            info!("Pass alerts enabled: {}", model.alerts.settings.enabled);
+            None
+        }
+    }
+}
+
+/// Raises every alert and runs every hook that fell due since the last check, unless the clock jumped too far to be a live pass.
+/// Hooks only run in simulated time if they opt in.
+fn check_alerts(model: &mut Model) {
+    let now = model.clock.now();
+    let last_check = model.alerts.last_check;
+    model.alerts.last_check = now;
+    model.hooks.collect();
+    if model
+        .alerts
+        .banner
+        .as_ref()
+        .is_some_and(|x| x.expires < Utc::now())
+    {
+        model.alerts.banner = None;
+    }
+    if now <= last_check
+        || now.signed_duration_since(last_check) > TimeDelta::seconds(MAX_CHECK_GAP)
+    {
+        return;
+    }
+    let Some(satellite) = model.current_satellite.as_ref() else {
+        return;
+    };
+    let name = satellite.satellite.get_name();
+    let norad_id = satellite.satellite.get_norad_id();
+    let mut raised = vec![];
+    let mut hooks = vec![];
+    let real_time = model.clock.is_real_time();
+    for pass in model.upcoming_passes.iter_mut() {
+        if model.alerts.settings.enabled {
+            for (event, time) in model.alerts.settings.events(pass) {
+                if time > last_check && time <= now && !pass.alerts.alerts.contains(&event) {
+                    pass.alerts.alerts.push(event);
+                    raised.push(alert_text(
+                        &name,
+                        pass,
+                        event,
+                        model.alerts.settings.lead_minutes,
+                    ));
+                }
+            }
+        }
+        for (index, hook) in model.hooks.hooks.iter().enumerate() {
+            let time = hook.time(pass);
+            if hook.applies_to(pass, norad_id)
+                && (real_time || hook.simulated)
+                && time > last_check
+                && time <= now
+                && !pass.alerts.hooks.contains(&index)
+            {
+                pass.alerts.hooks.push(index);
+                hooks.push((index, pass.clone()));
+            }
+        }
+    }
+    for text in raised {
+        raise_alert(model, &text);
+    }
+    for (index, pass) in hooks {
+        start_hook(model, index, &pass);
+    }
+}
+
+/// Environment passed to hook commands describing the pass and where the satellite is now
+#[cfg(not(target_arch = "wasm32"))]
+fn hook_env(model: &Model, event: PassEvent, pass: &TLPass) -> Vec<(String, String)> {
+    use crate::pass_plan::look_at;
+
+    let mut env = vec![];
+    let Some(satellite) = model.current_satellite.as_ref() else {
+        return env;
+    };
+    let now = model.clock.now();
+    let pointing = look_at(&satellite.satellite, &pass.station, now.timestamp());
+    let time_format = "%Y-%m-%dT%H:%M:%SZ";
+    let values = [
+        ("EVENT", format!("{:?}", event).to_uppercase()),
+        ("SATELLITE", satellite.satellite.get_name()),
+        ("NORAD_ID", satellite.satellite.get_norad_id().to_string()),
+        ("STATION", pass.station.name.clone()),
+        ("STATION_LAT", pass.station.lat.to_string()),
+        ("STATION_LONG", pass.station.long.to_string()),
+        (
+            "AOS",
+            pass.pass.get_aos_datetime().format(time_format).to_string(),
+        ),
+        (
+            "TME",
+            pass.pass.get_tme_datetime().format(time_format).to_string(),
+        ),
+        (
+            "LOS",
+            pass.pass.get_los_datetime().format(time_format).to_string(),
+        ),
+        ("DURATION", pass.pass.get_duration_sec().to_string()),
+        (
+            "MAX_ELEVATION",
+            format!("{:.2}", pass.pass.get_max_elevation()),
+        ),
+        ("AZIMUTH", format!("{:.2}", pointing.azimuth)),
+        ("ELEVATION", format!("{:.2}", pointing.elevation)),
+        ("RANGE", format!("{:.2}", pointing.range)),
+        ("TIME", now.format(time_format).to_string()),
+        ("SIMULATED", (!model.clock.is_real_time()).to_string()),
+    ];
+    for (key, value) in values {
+        env.push((format!("TRACKELLITE_{}", key), value));
+    }
+    env
+}
+
+fn start_hook(model: &mut Model, index: usize, pass: &TLPass) {
+    let hook = model.hooks.hooks[index].clone();
+    info!(
+        "Running {:?} hook for {}: {}",
+        hook.event, pass.station.name, hook.command
+    );
+    model.hooks.push(LogEntry {
+        time: Utc::now(),
+        text: format!(
+            "{:?} at {}: running `{}`",
+            hook.event, pass.station.name, hook.command
+        ),
+        error: false,
+    });
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::hooks::run_hook;
+
+        let env = hook_env(model, hook.event, pass);
+        run_hook(hook.command, env, model.hooks.sender.clone());
+    }
+    #[cfg(target_arch = "wasm32")]
+    model.hooks.push(LogEntry {
+        time: Utc::now(),
+        text: "Hooks cannot run commands in the browser".to_string(),
+        error: true,
+    });
+}
+
+fn alert_text(name: &str, pass: &TLPass, event: PassEvent, lead_minutes: i64) -> String {
+    match event {
+        PassEvent::PreAos => format!(
+            "{}: AOS at {} in {} min, Max. El {:.1}deg",
+            name,
+            pass.station.name,
+            lead_minutes,
+            pass.pass.get_max_elevation()
+        ),
+        PassEvent::Aos => format!(
+            "{}: AOS at {}, Max. El {:.1}deg",
+            name,
+            pass.station.name,
+            pass.pass.get_max_elevation()
+        ),
+        PassEvent::Tme => format!("{}: TME at {}", name, pass.station.name),
+        PassEvent::Los => format!("{}: LOS at {}", name, pass.station.name),
+    }
+}
+
+fn raise_alert(model: &mut Model, text: &str) {
+    info!("Pass alert: {}", text);
+    let settings = &model.alerts.settings;
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::alerts::{desktop_notify, ring_bell};
+
+        if settings.bell {
+            ring_bell();
+        }
+        if settings.desktop {
+            desktop_notify("Pass alert", text);
+        }
+    }
+    if settings.banner {
+        model.alerts.banner = Some(Banner {
+            text: text.to_string(),
+            expires: Utc::now() + TimeDelta::seconds(BANNER_DURATION),
+        });
+    }
+}
@@ -0,0 +1,47 @@
+use chrono::TimeDelta;
+use tracing::info;
+
+use crate::structs::{ClockMsg, Message, Model};
+
+/// Lead time before AOS when jumping to the next pass, so the countdown is still visible
+const AOS_JUMP_LEAD: i64 = 30;
+
+pub fn parse_clock_msg(model: &mut Model, clock_msg: ClockMsg) -> Option<Message> {
+    match clock_msg {
+        ClockMsg::Scrub(seconds) => {
+            model.clock.shift(TimeDelta::seconds(seconds));
+            Some(Message::PropagatePasses)
+        }
+        ClockMsg::Faster => {
+            model.clock.faster();
+            None
+        }
+        ClockMsg::Slower => {
+            model.clock.slower();
+            None
+        }
+        ClockMsg::TogglePause => {
+            model.clock.toggle_pause();
+            None
+        }
+        ClockMsg::JumpNextAOS => {
+            let now = model.clock.now();
+            if let Some(x) = model
+                .upcoming_passes
+                .iter()
+                .find(|x| x.pass.get_aos_datetime() > now)
+            {
+                info!("Jumping clock to AOS at {}", x.pass.get_aos_datetime());
+                model
+                    .clock
+                    .jump_to(x.pass.get_aos_datetime() - TimeDelta::seconds(AOS_JUMP_LEAD));
+                return Some(Message::PropagatePasses);
+            }
+            None
+        }
+        ClockMsg::RealTime => {
+            model.clock.reset();
+            Some(Message::PropagatePasses)
+        }
+    }
+}
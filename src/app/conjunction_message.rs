@@ -0,0 +1,75 @@
+use tracing::info;
+
+use crate::conjunction::screen;
+use crate::structs::{ConjunctionMsg, CurrentMsg, ListMovement, Message, Model};
+
+pub fn parse_conjunction_msg(
+    model: &mut Model,
+    conjunction_msg: ConjunctionMsg,
+) -> Option<Message> {
+    let conjunctions = &mut model.conjunctions;
+    match conjunction_msg {
+        ConjunctionMsg::ListMovement(ListMovement::Up) => conjunctions.table_state.scroll_up_by(1),
+        ConjunctionMsg::ListMovement(ListMovement::Down) => {
+            let last = conjunctions.results.len().saturating_sub(1);
+            let selection = conjunctions
+                .table_state
+                .selected()
+                .map_or(0, |x| (x + 1).min(last));
+            conjunctions.table_state.select(Some(selection));
+        }
+        ConjunctionMsg::ListMovement(_) => {}
+        ConjunctionMsg::Run => {
+            if conjunctions.running {
+                return None;
+            }
+            let satellites = model.sat_config.satellite_list.clone();
+            let (start, window, threshold) = (
+                model.clock.now(),
+                conjunctions.window(),
+                conjunctions.threshold(),
+            );
+            info!("Screening {} satellites", satellites.len());
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                conjunctions.running = true;
+                conjunctions.current_message =
+                    CurrentMsg::message(&format!("Screening {} satellites…", satellites.len()));
+                let sender = conjunctions.sender.clone();
+                std::thread::spawn(move || {
+                    let _ = sender.send(screen(&satellites, start, window, threshold));
+                });
+            }
+            #[cfg(target_arch = "wasm32")]
+            return Some(Message::ConjunctionMsg(ConjunctionMsg::Results(screen(
+                &satellites,
+                start,
+                window,
+                threshold,
+            ))));
+        }
+        ConjunctionMsg::Results(screening) => {
+            info!("Screened {} satellites", screening.satellites);
+            conjunctions.running = false;
+            conjunctions.table_state.select(Some(0));
+            conjunctions.current_message = CurrentMsg::message(&format!(
+                "Found {} approaches under {}km between {} satellites",
+                screening.results.len(),
+                screening.threshold,
+                screening.satellites
+            ));
+            conjunctions.results = screening.results;
+        }
+        ConjunctionMsg::CycleWindow => conjunctions.window += 1,
+        ConjunctionMsg::CycleThreshold => conjunctions.threshold += 1,
+        ConjunctionMsg::CycleSort => {
+            conjunctions.sort = conjunctions.sort.next();
+            conjunctions.table_state.select(Some(0));
+        }
+        ConjunctionMsg::ReverseSort => {
+            conjunctions.descending = !conjunctions.descending;
+            conjunctions.table_state.select(Some(0));
+        }
+    }
+    None
+}
@@ -1,6 +1,13 @@
 #[cfg(not(target_arch = "wasm32"))]
 pub mod cache {
+    use crate::alerts::AlertSettings;
+    use crate::history::HistoryEntry;
+    use crate::hooks::Hook;
+    use crate::keymap::KeymapFile;
+    use crate::link_budget::LinkSettings;
+    use crate::sat_groups::SatTags;
     use crate::structs::{MetaData, TLSatellite};
+    use crate::theme::ThemeFile;
     use crate::utils::native::get_data_dir;
     use tracing::{debug, info};
 
@@ -21,6 +28,8 @@ pub mod cache {
 
     use std::collections::HashMap;
 
+    use std::path::PathBuf;
+
     use color_eyre::Result;
 
     pub fn get_sup_data_spacetrack(norad_id: &str) -> Result<MetaData> {
@@ -86,6 +95,139 @@ pub mod cache {
         Ok(())
     }
 
+    /// Alert settings from `alerts.json`, written out with the defaults if it does not exist yet
+    pub fn get_alert_settings() -> Result<AlertSettings> {
+        let mut settings_file = get_data_dir();
+        settings_file.push("alerts.json");
+        if settings_file.try_exists()? {
+            let file = File::open(settings_file)?;
+            let reader = BufReader::new(file);
+            Ok(from_reader(reader)?)
+        } else {
+            let file = File::create_new(settings_file)?;
+            let writer = BufWriter::new(file);
+            serde_json::to_writer_pretty(writer, &AlertSettings::default())?;
+            Ok(AlertSettings::default())
+        }
+    }
+
+    /// Hook commands from `hooks.json`, written out empty if it does not exist yet
+    pub fn get_hooks() -> Result<Vec<Hook>> {
+        let mut hooks_file = get_data_dir();
+        hooks_file.push("hooks.json");
+        if hooks_file.try_exists()? {
+            let file = File::open(hooks_file)?;
+            let reader = BufReader::new(file);
+            Ok(from_reader(reader)?)
+        } else {
+            let file = File::create_new(hooks_file)?;
+            let writer = BufWriter::new(file);
+            to_writer(writer, &Vec::<Hook>::new())?;
+            Ok(vec![])
+        }
+    }
+
+    /// Completed passes from `history.json`, empty if nothing has been recorded yet
+    pub fn get_history() -> Result<Vec<HistoryEntry>> {
+        let mut history_file = get_data_dir();
+        history_file.push("history.json");
+        if history_file.try_exists()? {
+            let file = File::open(history_file)?;
+            let reader = BufReader::new(file);
+            Ok(from_reader(reader)?)
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    pub fn cache_history(data: &[HistoryEntry]) -> Result<()> {
+        let mut history_file = get_data_dir();
+        history_file.push("history.json");
+        let file = File::create(history_file)?;
+        let writer = BufWriter::new(file);
+        info!("Writing pass history: {} entries", data.len());
+        to_writer(writer, data)?;
+        Ok(())
+    }
+
+    /// Link budget settings from `link.json`, written out with the defaults if it does not exist yet
+    pub fn get_link_settings() -> Result<LinkSettings> {
+        let mut settings_file = get_data_dir();
+        settings_file.push("link.json");
+        if settings_file.try_exists()? {
+            let file = File::open(settings_file)?;
+            let reader = BufReader::new(file);
+            Ok(from_reader(reader)?)
+        } else {
+            let file = File::create_new(settings_file)?;
+            let writer = BufWriter::new(file);
+            serde_json::to_writer_pretty(writer, &LinkSettings::default())?;
+            Ok(LinkSettings::default())
+        }
+    }
+
+    /// Key binding overrides from `keymap.json`, none if the file does not exist
+    pub fn get_keymap() -> Result<KeymapFile> {
+        let mut keymap_file = get_data_dir();
+        keymap_file.push("keymap.json");
+        if keymap_file.try_exists()? {
+            let file = File::open(keymap_file)?;
+            let reader = BufReader::new(file);
+            Ok(from_reader(reader)?)
+        } else {
+            Ok(KeymapFile::default())
+        }
+    }
+
+    /// Theme selection from `theme.json`, the dark theme if the file does not exist
+    pub fn get_theme() -> Result<ThemeFile> {
+        let mut theme_file = get_data_dir();
+        theme_file.push("theme.json");
+        if theme_file.try_exists()? {
+            let file = File::open(theme_file)?;
+            let reader = BufReader::new(file);
+            Ok(from_reader(reader)?)
+        } else {
+            Ok(ThemeFile::default())
+        }
+    }
+
+    /// Favourites and groups from `groups.json`, empty if none have been set yet
+    pub fn get_sat_tags() -> Result<SatTags> {
+        let mut tags_file = get_data_dir();
+        tags_file.push("groups.json");
+        if tags_file.try_exists()? {
+            let file = File::open(tags_file)?;
+            let reader = BufReader::new(file);
+            Ok(from_reader(reader)?)
+        } else {
+            Ok(SatTags::default())
+        }
+    }
+
+    pub fn cache_sat_tags(data: &SatTags) -> Result<()> {
+        let mut tags_file = get_data_dir();
+        tags_file.push("groups.json");
+        let file = File::create(tags_file)?;
+        let writer = BufWriter::new(file);
+        info!("Writing satellite groups");
+        serde_json::to_writer_pretty(writer, data)?;
+        Ok(())
+    }
+
+    /// Writes `contents` to the exports folder in the data directory, returning the path written
+    pub fn export_file(filename: &str, contents: &str) -> Result<PathBuf> {
+        let mut export_dir = get_data_dir();
+        export_dir.push("exports");
+        std::fs::DirBuilder::new()
+            .recursive(true)
+            .create(&export_dir)?;
+        export_dir.push(filename);
+        std::fs::write(&export_dir, contents)?;
+        info!("Exported {:?}", &export_dir);
+        Ok(export_dir)
+    }
+
     pub fn cache_tle(data: &Vec<TLSatellite>) -> Result<()> {
         let cache_result = get_cache_file("tle.json");
         let mut cache_data;
@@ -108,7 +250,14 @@ pub mod cache {
 }
 #[cfg(target_arch = "wasm32")]
 pub mod cache {
+    use crate::alerts::AlertSettings;
+    use crate::history::HistoryEntry;
+    use crate::hooks::Hook;
+    use crate::keymap::KeymapFile;
+    use crate::link_budget::LinkSettings;
+    use crate::sat_groups::SatTags;
     use crate::structs::{MetaData, TLSatellite};
+    use crate::theme::ThemeFile;
 
     use color_eyre::eyre::eyre;
     use serde_json::from_str;
@@ -203,4 +352,93 @@ pub mod cache {
     pub fn get_gs_cache() -> Result<HashMap<String, String>> {
         get_data_from_cache("gs")
     }
+    pub fn get_hooks() -> Result<Vec<Hook>> {
+        Ok(vec![])
+    }
+    pub fn get_link_settings() -> Result<LinkSettings> {
+        let window = web_sys::window().unwrap();
+        let data = window
+            .local_storage()
+            .map_err(|_| Error::msg("Unable to get link settings from cache"))?
+            .unwrap()
+            .get_item("link")
+            .map_err(|_| Error::msg("Unable to get link settings from cache"))?;
+        match data {
+            Some(x) => Ok(from_str(&x)?),
+            None => Ok(LinkSettings::default()),
+        }
+    }
+    pub fn get_history() -> Result<Vec<HistoryEntry>> {
+        let window = web_sys::window().unwrap();
+        let data = window
+            .local_storage()
+            .map_err(|_| Error::msg("Unable to get pass history from cache"))?
+            .unwrap()
+            .get_item("history")
+            .map_err(|_| Error::msg("Unable to get pass history from cache"))?;
+        match data {
+            Some(x) => Ok(from_str(&x)?),
+            None => Ok(vec![]),
+        }
+    }
+    pub fn cache_history(data: &[HistoryEntry]) -> Result<()> {
+        info!("Writing pass history: {} entries", data.len());
+        put_data_in_cache("history", &to_string(data)?)
+    }
+    pub fn get_keymap() -> Result<KeymapFile> {
+        let window = web_sys::window().unwrap();
+        let data = window
+            .local_storage()
+            .map_err(|_| Error::msg("Unable to get keymap from cache"))?
+            .unwrap()
+            .get_item("keymap")
+            .map_err(|_| Error::msg("Unable to get keymap from cache"))?;
+        match data {
+            Some(x) => Ok(from_str(&x)?),
+            None => Ok(KeymapFile::default()),
+        }
+    }
+    pub fn get_theme() -> Result<ThemeFile> {
+        let window = web_sys::window().unwrap();
+        let data = window
+            .local_storage()
+            .map_err(|_| Error::msg("Unable to get theme from cache"))?
+            .unwrap()
+            .get_item("theme")
+            .map_err(|_| Error::msg("Unable to get theme from cache"))?;
+        match data {
+            Some(x) => Ok(from_str(&x)?),
+            None => Ok(ThemeFile::default()),
+        }
+    }
+    pub fn get_sat_tags() -> Result<SatTags> {
+        let window = web_sys::window().unwrap();
+        let data = window
+            .local_storage()
+            .map_err(|_| Error::msg("Unable to get satellite groups from cache"))?
+            .unwrap()
+            .get_item("groups")
+            .map_err(|_| Error::msg("Unable to get satellite groups from cache"))?;
+        match data {
+            Some(x) => Ok(from_str(&x)?),
+            None => Ok(SatTags::default()),
+        }
+    }
+    pub fn cache_sat_tags(data: &SatTags) -> Result<()> {
+        info!("Writing satellite groups");
+        put_data_in_cache("groups", &to_string(data)?)
+    }
+    pub fn get_alert_settings() -> Result<AlertSettings> {
+        let window = web_sys::window().unwrap();
+        let data = window
+            .local_storage()
+            .map_err(|_| Error::msg("Unable to get alert settings from cache"))?
+            .unwrap()
+            .get_item("alerts")
+            .map_err(|_| Error::msg("Unable to get alert settings from cache"))?;
+        match data {
+            Some(x) => Ok(from_str(&x)?),
+            None => Ok(AlertSettings::default()),
+        }
+    }
 }
@@ -31,6 +31,8 @@ pub fn parse_gsconfig_msg(model: &mut Model, gsconfig_msg: GSConfigMsg) -> Optio
                                 model.station_config.station_list.push(TLGroundStation {
                                     station: GroundStation::new([0.0, 0.0, 0.0], ""),
                                     active: false,
+                                    elevation_mask: 0.0,
+                                    utc_offset: None,
                                 });
                                 model.station_config.table_state.select_first_column();
                                 model.station_config.table_state.scroll_right_by(1);
@@ -51,6 +53,18 @@ pub fn parse_gsconfig_msg(model: &mut Model, gsconfig_msg: GSConfigMsg) -> Optio
                     GSconfigState::CellEdit => None,
                 }
             }
+            ListMovement::Click(x) => {
+                if model.station_config.editing != GSconfigState::RowSelect {
+                    return None;
+                }
+                if model.station_config.table_state.selected() == Some(x) {
+                    return Some(Message::GSConfigMsg(GSConfigMsg::ListMovement(
+                        ListMovement::Select,
+                    )));
+                }
+                model.station_config.table_state.select(Some(x));
+                None
+            }
             ListMovement::Left => {
                 if let Some(x) = model.station_config.table_state.selected_column() {
                     if x == 1 {
@@ -62,7 +76,7 @@ pub fn parse_gsconfig_msg(model: &mut Model, gsconfig_msg: GSConfigMsg) -> Optio
             }
             ListMovement::Right => {
                 if let Some(x) = model.station_config.table_state.selected_column() {
-                    if x == 4 {
+                    if x == 6 {
                         return None;
                     }
                 }
@@ -98,7 +112,7 @@ pub fn parse_gsconfig_msg(model: &mut Model, gsconfig_msg: GSConfigMsg) -> Optio
                             model.station_config.current_edit_buffer.push(x)
                         }
                     }
-                    2..5 => {
+                    2..7 => {
                         if let KeyCode::Char(x) = letter {
                             if x.is_numeric() || x == '.' {
                                 model.station_config.current_edit_buffer.push(x);
@@ -146,6 +160,14 @@ fn handle_stop_editing(model: &mut Model) -> Option<Message> {
                     .name = model.station_config.current_edit_buffer.clone();
                 model.station_config.editing = GSconfigState::CellSelect;
                 model.station_config.current_edit_buffer = "".to_string();
+            } else if y == 6 && model.station_config.current_edit_buffer.is_empty() {
+                //an empty UTC offset falls back to local mean time
+                model
+                    .station_config
+                    .station_list
+                    .get_mut(x)
+                    .unwrap()
+                    .utc_offset = None;
             } else {
                 let value_test = model.station_config.current_edit_buffer.parse::<f64>();
 
@@ -165,6 +187,13 @@ fn handle_stop_editing(model: &mut Model) -> Option<Message> {
                     model.station_config.current_msg =
                         CurrentMsg::error("Longitude value out of range");
                     return None;
+                } else if y == 5 && !(0.0..90.0).contains(&value) {
+                    model.station_config.current_msg =
+                        CurrentMsg::error("Elevation mask out of range");
+                    return None;
+                } else if y == 6 && !(-12.0..=14.0).contains(&value) {
+                    model.station_config.current_msg = CurrentMsg::error("UTC offset out of range");
+                    return None;
                 }
                 match y {
                     2 => {
@@ -194,10 +223,26 @@ fn handle_stop_editing(model: &mut Model) -> Option<Message> {
                             .station
                             .alt = value
                     }
+                    5 => {
+                        model
+                            .station_config
+                            .station_list
+                            .get_mut(x)
+                            .unwrap()
+                            .elevation_mask = value
+                    }
+                    6 => {
+                        model
+                            .station_config
+                            .station_list
+                            .get_mut(x)
+                            .unwrap()
+                            .utc_offset = Some(value)
+                    }
                     _ => {}
                 };
             }
-            if model.station_config.table_state.selected_column().unwrap() < 4 {
+            if model.station_config.table_state.selected_column().unwrap() < 6 {
                 model.station_config.table_state.scroll_right_by(1);
                 model.station_config.current_edit_buffer = "".to_string();
             } else {
@@ -213,7 +258,7 @@ fn handle_cell_select(model: &mut Model) {
     if let Some(index) = model.station_config.table_state.selected() {
         if let Some(column) = model.station_config.table_state.selected_column() {
             match column {
-                1..5 => {
+                1..7 => {
                     model.station_config.editing = GSconfigState::CellEdit;
                 }
                 _ => {
@@ -0,0 +1,164 @@
+#[cfg(not(target_arch = "wasm32"))]
+use ratatui::crossterm::event::KeyCode;
+
+#[cfg(target_arch = "wasm32")]
+use ratzilla::event::KeyCode;
+
+use std::cmp::Reverse;
+
+use chrono::{DateTime, Utc};
+use tracing::{info, warn};
+
+use super::file_cache::cache::cache_history;
+use crate::history::{HistoryEntry, history_to_csv};
+use crate::structs::{CurrentMsg, HistoryMsg, ListMovement, Message, Model, TLPass};
+
+pub fn parse_history_msg(model: &mut Model, history_msg: HistoryMsg) -> Option<Message> {
+    let history = &mut model.history;
+    match history_msg {
+        HistoryMsg::ListMovement(list_movement) => {
+            match list_movement {
+                ListMovement::Up => history.table_state.scroll_up_by(1),
+                ListMovement::Down => {
+                    let last = history.entries.len().saturating_sub(1);
+                    let selection = history
+                        .table_state
+                        .selected()
+                        .map_or(0, |x| (x + 1).min(last));
+                    history.table_state.select(Some(selection));
+                }
+                _ => {}
+            }
+            None
+        }
+        HistoryMsg::CycleOutcome => {
+            if let Some(x) = selected_entry(model) {
+                x.outcome = x.outcome.next();
+                save_history(model);
+            }
+            None
+        }
+        HistoryMsg::ToggleEditing => {
+            if history.editing {
+                history.editing = false;
+                let notes = history.edit_buffer.clone();
+                if let Some(x) = selected_entry(model) {
+                    x.notes = notes;
+                    save_history(model);
+                }
+            } else if let Some(notes) = selected_entry(model).map(|x| x.notes.clone()) {
+                model.history.edit_buffer = notes;
+                model.history.editing = true;
+            }
+            None
+        }
+        HistoryMsg::LetterTyped(key) => {
+            if let KeyCode::Char(x) = key {
+                history.edit_buffer.push(x);
+            }
+            None
+        }
+        HistoryMsg::Backspace => {
+            history.edit_buffer.pop();
+            None
+        }
+        HistoryMsg::Export => {
+            export_history(model);
+            None
+        }
+        HistoryMsg::CopyLog => {
+            let csv = history_to_csv(&model.history.entries);
+            match model.sat_config.clipboard.set_text(&csv) {
+                Ok(_) => {
+                    model.history.current_message =
+                        CurrentMsg::message("Copied pass history to clipboard")
+                }
+                Err(_) => {
+                    model.history.current_message =
+                        CurrentMsg::error("Failed to copy to clipboard!")
+                }
+            }
+            None
+        }
+    }
+}
+
+/// Adds passes that have finished since the last propagation to the history. Only passes flown in real time are real contacts.
+pub fn record_history(model: &mut Model, now: DateTime<Utc>) {
+    if !model.clock.is_real_time() {
+        return;
+    }
+    let finished: Vec<&TLPass> = model
+        .upcoming_passes
+        .iter()
+        .filter(|x| x.pass.get_los_datetime() <= now)
+        .collect();
+    let mut added = false;
+    for i in finished {
+        let Some(satellite) = model
+            .sat_config
+            .satellite_list
+            .iter()
+            .find(|x| x.satellite.get_norad_id() == i.norad_id)
+        else {
+            continue;
+        };
+        let entry = HistoryEntry::new(&satellite.satellite.get_name(), i);
+        if !model.history.entries.iter().any(|x| x.same_pass(&entry)) {
+            info!("Recording pass over {} in history", entry.station);
+            model.history.entries.push(entry);
+            added = true;
+        }
+    }
+    if added {
+        // Keep the selection, and any notes being edited, on the same pass once the new ones are sorted in
+        let selected = model
+            .history
+            .table_state
+            .selected()
+            .and_then(|x| model.history.entries.get(x))
+            .cloned();
+        model.history.entries.sort_by_key(|x| Reverse(x.aos));
+        if let Some(x) = selected {
+            let index = model.history.entries.iter().position(|y| y.same_pass(&x));
+            model.history.table_state.select(index);
+        }
+        save_history(model);
+    }
+}
+
+fn selected_entry(model: &mut Model) -> Option<&mut HistoryEntry> {
+    let index = model.history.table_state.selected()?;
+    model.history.entries.get_mut(index)
+}
+
+fn save_history(model: &mut Model) {
+    if let Err(x) = cache_history(&model.history.entries) {
+        warn!("{}", x);
+        model.history.current_message = CurrentMsg::error("Unable to save pass history");
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn export_history(model: &mut Model) {
+    use super::file_cache::cache::export_file;
+
+    let filename = format!("history_{}.csv", Utc::now().format("%Y%m%dT%H%M%SZ"));
+    match export_file(&filename, &history_to_csv(&model.history.entries)) {
+        Ok(path) => {
+            model.history.current_message =
+                CurrentMsg::message(&format!("Exported to {}", path.display()))
+        }
+        Err(x) => {
+            warn!("{}", x);
+            model.history.current_message = CurrentMsg::error("Failed to export pass history");
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn export_history(model: &mut Model) {
+    warn!("File export is unavailable in the browser");
+    model.history.current_message =
+        CurrentMsg::error("Export to file is unavailable in the browser, copy the log instead");
+}
@@ -4,45 +4,66 @@ use ratatui::crossterm::event::{self, Event, KeyCode};
 #[cfg(target_arch = "wasm32")]
 use ratzilla::event::{self, KeyCode, KeyEvent};
 
+use crate::keymap::{Action, Key, KeyContext};
 use crate::structs::AddSatMsg;
+use crate::structs::AlertMsg;
 use crate::structs::AppState;
+use crate::structs::ClockMsg;
+use crate::structs::ConjunctionMsg;
 use crate::structs::GSConfigMsg;
 use crate::structs::GSconfigState;
+use crate::structs::HistoryMsg;
+use crate::structs::LinkMsg;
 use crate::structs::ListMovement;
+use crate::structs::MapMsg;
 use crate::structs::Message;
 use crate::structs::Model;
+use crate::structs::PassDetailMsg;
 use crate::structs::SatList;
+use crate::structs::ScheduleMsg;
 use color_eyre::Result;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub fn handle_event(model: &Model) -> Result<Option<Message>> {
-    use chrono::Utc;
-
     use chrono::TimeDelta;
     use tracing::info;
 
+    use super::mouse_handle::{Mouse, handle_mouse};
+
     use std::time::Duration;
 
+    if let Some(x) = model.conjunctions.finished() {
+        return Ok(Some(Message::ConjunctionMsg(ConjunctionMsg::Results(x))));
+    }
+    if let Some(x) = model.sat_config.groups.finished() {
+        return Ok(Some(Message::SatListMessage(SatList::GroupFetched(x))));
+    }
     if event::poll(Duration::from_millis(250))? {
-        if let Event::Key(key) = event::read()? {
-            if key.kind == event::KeyEventKind::Press {
-                match model.current_state {
-                    AppState::Base => return Ok(handle_key_base(key)),
-                    AppState::SatSelect => return Ok(handle_key_sat_config(key)),
-                    AppState::SatAddition => {
-                        return Ok(handle_key_sat_addition(key, model));
-                    }
-                    AppState::GSConfig => return Ok(handle_key_gs_config(key, model)),
-                }
+        match event::read()? {
+            Event::Key(key) if key.kind == event::KeyEventKind::Press => {
+                return Ok(handle_key(key, model));
+            }
+            Event::Mouse(mouse) => {
+                return Ok(Mouse::from_event(&mouse).and_then(|x| handle_mouse(model, x)));
             }
+            _ => {}
         }
-    } else if let Some(x) = model.upcoming_passes.first() {
-        if x.pass.get_los_datetime().signed_duration_since(Utc::now())
-            < TimeDelta::new(-30, 0).unwrap()
-        {
-            info!("Re-propagating to remove old pass");
-            return Ok(Some(Message::PropagatePasses));
+    } else {
+        if let Some(x) = model.upcoming_passes.first() {
+            let now = model.clock.now();
+            if x.pass.get_los_datetime().signed_duration_since(now)
+                < TimeDelta::new(-30, 0).unwrap()
+            {
+                info!("Re-propagating to remove old pass");
+                return Ok(Some(Message::PropagatePasses));
+            } else if model.pass_window_start.signed_duration_since(now)
+                > TimeDelta::new(600, 0).unwrap()
+            {
+                info!("Re-propagating after clock moved before the pass window");
+                return Ok(Some(Message::PropagatePasses));
+            }
         }
+        return Ok(Some(Message::AlertMsg(AlertMsg::Check)));
     }
     Ok(None)
 }
@@ -50,101 +71,258 @@ pub fn handle_event(model: &Model) -> Result<Option<Message>> {
 use std::sync::mpsc::Sender;
 #[cfg(target_arch = "wasm32")]
 pub fn handle_event(model: &Model, key_event: KeyEvent, tx: Sender<Message>) {
-    let message;
-    match model.current_state {
-        AppState::Base => message = handle_key_base(key_event),
-        AppState::SatSelect => message = handle_key_sat_config(key_event),
-        AppState::SatAddition => {
-            message = handle_key_sat_addition(key_event, &model);
-        }
-        AppState::GSConfig => message = handle_key_gs_config(key_event, &model),
-        AppState::SatWaitingFetch => message = None,
-    }
+    let message = handle_key(key_event, model);
     if let Some(x) = message {
         tx.send(x).unwrap()
     }
 }
 
-fn handle_key_gs_config(key: event::KeyEvent, model: &Model) -> Option<Message> {
-    match model.station_config.editing {
-        GSconfigState::CellSelect => match key.code {
-            KeyCode::Left => Some(Message::GSConfigMsg(GSConfigMsg::ListMovement(
-                ListMovement::Left,
-            ))),
-            KeyCode::Right => Some(Message::GSConfigMsg(GSConfigMsg::ListMovement(
-                ListMovement::Right,
-            ))),
-            KeyCode::Enter => Some(Message::GSConfigMsg(GSConfigMsg::ListMovement(
-                ListMovement::Select,
-            ))),
-            KeyCode::Esc | KeyCode::Char('q') => Some(Message::GSConfigMsg(GSConfigMsg::Back)),
-            _ => None,
-        },
-        GSconfigState::RowSelect => match key.code {
-            KeyCode::Char('q') | KeyCode::Esc => Some(Message::ToggleGSConfig),
-            KeyCode::Up => Some(Message::GSConfigMsg(GSConfigMsg::ListMovement(
-                ListMovement::Up,
-            ))),
-            KeyCode::Down => Some(Message::GSConfigMsg(GSConfigMsg::ListMovement(
-                ListMovement::Down,
-            ))),
-            KeyCode::Enter => Some(Message::GSConfigMsg(GSConfigMsg::ListMovement(
-                ListMovement::Select,
-            ))),
-            KeyCode::Char('a') => Some(Message::GSConfigMsg(GSConfigMsg::Activate)),
-            _ => None,
-        },
-        GSconfigState::CellEdit => match key.code {
-            KeyCode::Esc | KeyCode::Char('q') => Some(Message::GSConfigMsg(GSConfigMsg::Back)),
-            KeyCode::Backspace => Some(Message::GSConfigMsg(GSConfigMsg::Backspace)),
-            KeyCode::Enter => Some(Message::GSConfigMsg(GSConfigMsg::StopEditing)),
-            _ => Some(Message::GSConfigMsg(GSConfigMsg::LetterTyped(key.code))),
-        },
+/// Text entry modes take every key, otherwise the key is looked up in the keymap for the current context
+fn handle_key(key: event::KeyEvent, model: &Model) -> Option<Message> {
+    match model.current_state {
+        AppState::SatSelect if model.sat_config.search.editing => handle_key_search(key),
+        AppState::SatSelect if model.sat_config.groups.editing => handle_key_groups(key),
+        AppState::SatAddition if model.sat_config.add_sat.editing => handle_key_sat_addition(key),
+        AppState::GSConfig if model.station_config.editing == GSconfigState::CellEdit => {
+            handle_key_cell_edit(key)
+        }
+        AppState::History if model.history.editing => handle_key_history_notes(key),
+        _ => {
+            let (context, action) = model
+                .keymap
+                .resolve(KeyContext::of(model)?, Key::from_code(&key.code)?)?;
+            action_message(context, action)
+        }
     }
 }
 
-fn handle_key_sat_config(key: event::KeyEvent) -> Option<Message> {
+fn handle_key_search(key: event::KeyEvent) -> Option<Message> {
     match key.code {
-        KeyCode::Char('q') | KeyCode::Esc => Some(Message::ToggleSatConfig),
-        KeyCode::Char('c') => Some(Message::SatListMessage(SatList::CopyTLE)),
-        KeyCode::Char('f') => Some(Message::SatListMessage(SatList::FetchTLE)),
+        KeyCode::Esc => Some(Message::SatListMessage(SatList::ClearSearch)),
+        KeyCode::Enter => Some(Message::SatListMessage(SatList::StopSearch)),
+        KeyCode::Backspace => Some(Message::SatListMessage(SatList::SearchBackspace)),
         KeyCode::Up => Some(Message::SatListMessage(SatList::ListMovement(
             ListMovement::Up,
         ))),
         KeyCode::Down => Some(Message::SatListMessage(SatList::ListMovement(
             ListMovement::Down,
         ))),
-        KeyCode::Enter => Some(Message::SatListMessage(SatList::ListMovement(
-            ListMovement::Select,
-        ))),
-        _ => None,
+        _ => Some(Message::SatListMessage(SatList::SearchTyped(key.code))),
     }
 }
 
-fn handle_key_base(key: event::KeyEvent) -> Option<Message> {
+fn handle_key_groups(key: event::KeyEvent) -> Option<Message> {
     match key.code {
-        KeyCode::Char('q') | KeyCode::Esc => Some(Message::Close),
-        KeyCode::Char('s') => Some(Message::ToggleSatConfig),
-        KeyCode::Char('g') => Some(Message::ToggleGSConfig),
-        _ => None,
+        KeyCode::Esc => Some(Message::SatListMessage(SatList::CancelGroups)),
+        KeyCode::Enter => Some(Message::SatListMessage(SatList::SaveGroups)),
+        KeyCode::Backspace => Some(Message::SatListMessage(SatList::GroupBackspace)),
+        _ => Some(Message::SatListMessage(SatList::GroupTyped(key.code))),
+    }
+}
+
+fn handle_key_sat_addition(key: event::KeyEvent) -> Option<Message> {
+    match key.code {
+        KeyCode::Esc => Some(Message::AddSatMessage(AddSatMsg::ToggleEditing)),
+        KeyCode::Backspace => Some(Message::AddSatMessage(AddSatMsg::Backspace)),
+        KeyCode::Enter => Some(Message::AddSatMessage(AddSatMsg::StopEditing)),
+        _ => Some(Message::AddSatMessage(AddSatMsg::LetterTyped(key.code))),
     }
 }
 
-fn handle_key_sat_addition(key: event::KeyEvent, model: &Model) -> Option<Message> {
-    if !model.sat_config.add_sat.editing {
-        match key.code {
-            KeyCode::Char('q') | KeyCode::Esc => Some(Message::ToggleSatConfig),
-            KeyCode::Enter => Some(Message::AddSatMessage(AddSatMsg::ToggleEditing)),
-            KeyCode::Up | KeyCode::Down => Some(Message::AddSatMessage(AddSatMsg::ChangeSelection)),
-            KeyCode::Char('v') => Some(Message::AddSatMessage(AddSatMsg::PasteTLE)),
+fn handle_key_cell_edit(key: event::KeyEvent) -> Option<Message> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => Some(Message::GSConfigMsg(GSConfigMsg::Back)),
+        KeyCode::Backspace => Some(Message::GSConfigMsg(GSConfigMsg::Backspace)),
+        KeyCode::Enter => Some(Message::GSConfigMsg(GSConfigMsg::StopEditing)),
+        _ => Some(Message::GSConfigMsg(GSConfigMsg::LetterTyped(key.code))),
+    }
+}
+
+fn handle_key_history_notes(key: event::KeyEvent) -> Option<Message> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Enter => Some(Message::HistoryMsg(HistoryMsg::ToggleEditing)),
+        KeyCode::Backspace => Some(Message::HistoryMsg(HistoryMsg::Backspace)),
+        _ => Some(Message::HistoryMsg(HistoryMsg::LetterTyped(key.code))),
+    }
+}
+
+/// The message an action sends in the context it was bound in
+pub fn action_message(context: KeyContext, action: Action) -> Option<Message> {
+    match context {
+        KeyContext::Global => match action {
+            Action::Help => Some(Message::ToggleHelp),
             _ => None,
+        },
+        KeyContext::Base => base_message(action),
+        KeyContext::SatSelect => {
+            let message = match action {
+                Action::Close => return Some(Message::ToggleSatConfig),
+                Action::Up => SatList::ListMovement(ListMovement::Up),
+                Action::Down => SatList::ListMovement(ListMovement::Down),
+                Action::Select => SatList::ListMovement(ListMovement::Select),
+                Action::Search => SatList::StartSearch,
+                Action::Sort => SatList::CycleSort,
+                Action::Copy => SatList::CopyTLE,
+                Action::Fetch => SatList::FetchTLE,
+                Action::Favourite => SatList::ToggleFavourite,
+                Action::Groups => SatList::EditGroups,
+                Action::Export => SatList::ExportGroup,
+                _ => return None,
+            };
+            Some(Message::SatListMessage(message))
         }
-    } else {
-        match key.code {
-            KeyCode::Esc => Some(Message::AddSatMessage(AddSatMsg::ToggleEditing)),
-            KeyCode::Backspace => Some(Message::AddSatMessage(AddSatMsg::Backspace)),
-            KeyCode::Enter => Some(Message::AddSatMessage(AddSatMsg::StopEditing)),
-            _ => Some(Message::AddSatMessage(AddSatMsg::LetterTyped(key.code))),
+        KeyContext::SatAddition => match action {
+            Action::Close => Some(Message::ToggleSatConfig),
+            Action::Edit => Some(Message::AddSatMessage(AddSatMsg::ToggleEditing)),
+            Action::Up | Action::Down => Some(Message::AddSatMessage(AddSatMsg::ChangeSelection)),
+            Action::Paste => Some(Message::AddSatMessage(AddSatMsg::PasteTLE)),
+            _ => None,
+        },
+        KeyContext::GSConfig => match action {
+            Action::Close => Some(Message::ToggleGSConfig),
+            Action::Up => Some(Message::GSConfigMsg(GSConfigMsg::ListMovement(
+                ListMovement::Up,
+            ))),
+            Action::Down => Some(Message::GSConfigMsg(GSConfigMsg::ListMovement(
+                ListMovement::Down,
+            ))),
+            Action::Select => Some(Message::GSConfigMsg(GSConfigMsg::ListMovement(
+                ListMovement::Select,
+            ))),
+            Action::Activate => Some(Message::GSConfigMsg(GSConfigMsg::Activate)),
+            _ => None,
+        },
+        KeyContext::GSCellSelect => match action {
+            Action::Close => Some(Message::GSConfigMsg(GSConfigMsg::Back)),
+            Action::Left => Some(Message::GSConfigMsg(GSConfigMsg::ListMovement(
+                ListMovement::Left,
+            ))),
+            Action::Right => Some(Message::GSConfigMsg(GSConfigMsg::ListMovement(
+                ListMovement::Right,
+            ))),
+            Action::Select => Some(Message::GSConfigMsg(GSConfigMsg::ListMovement(
+                ListMovement::Select,
+            ))),
+            _ => None,
+        },
+        KeyContext::PassDetail => match action {
+            Action::Close => Some(Message::PassDetailMsg(PassDetailMsg::Close)),
+            Action::Up => Some(Message::PassDetailMsg(PassDetailMsg::ListMovement(
+                ListMovement::Up,
+            ))),
+            Action::Down => Some(Message::PassDetailMsg(PassDetailMsg::ListMovement(
+                ListMovement::Down,
+            ))),
+            Action::Step => Some(Message::PassDetailMsg(PassDetailMsg::CycleStep)),
+            Action::Export => Some(Message::PassDetailMsg(PassDetailMsg::Export)),
+            Action::Copy => Some(Message::PassDetailMsg(PassDetailMsg::CopyTable)),
+            Action::LinkBudget => Some(Message::LinkMsg(LinkMsg::Open)),
+            _ => None,
+        },
+        KeyContext::Schedule => {
+            let message = match action {
+                Action::Close => return Some(Message::ToggleSchedule),
+                Action::Up => ScheduleMsg::ListMovement(ListMovement::Up),
+                Action::Down => ScheduleMsg::ListMovement(ListMovement::Down),
+                Action::Select => ScheduleMsg::ListMovement(ListMovement::Select),
+                Action::Sort => ScheduleMsg::CycleSort,
+                Action::Reverse => ScheduleMsg::ReverseSort,
+                Action::FilterStation => ScheduleMsg::CycleStation,
+                Action::FilterElevation => ScheduleMsg::CycleMinElevation,
+                Action::FilterDuration => ScheduleMsg::CycleMinDuration,
+                Action::FilterTimeOfDay => ScheduleMsg::CycleTimeOfDay,
+                Action::ClearFilters => ScheduleMsg::ClearFilters,
+                _ => return None,
+            };
+            Some(Message::ScheduleMsg(message))
+        }
+        KeyContext::Conjunctions => {
+            let message = match action {
+                Action::Close => return Some(Message::ToggleConjunctions),
+                Action::Up => ConjunctionMsg::ListMovement(ListMovement::Up),
+                Action::Down => ConjunctionMsg::ListMovement(ListMovement::Down),
+                Action::Run => ConjunctionMsg::Run,
+                Action::Window => ConjunctionMsg::CycleWindow,
+                Action::Threshold => ConjunctionMsg::CycleThreshold,
+                Action::Sort => ConjunctionMsg::CycleSort,
+                Action::Reverse => ConjunctionMsg::ReverseSort,
+                _ => return None,
+            };
+            Some(Message::ConjunctionMsg(message))
+        }
+        KeyContext::EventLog => match action {
+            Action::Close => Some(Message::ToggleEventLog),
+            Action::Up => Some(Message::EventLogMsg(ListMovement::Up)),
+            Action::Down => Some(Message::EventLogMsg(ListMovement::Down)),
+            _ => None,
+        },
+        KeyContext::History => {
+            let message = match action {
+                Action::Close => return Some(Message::ToggleHistory),
+                Action::Up => HistoryMsg::ListMovement(ListMovement::Up),
+                Action::Down => HistoryMsg::ListMovement(ListMovement::Down),
+                Action::Edit => HistoryMsg::ToggleEditing,
+                Action::Outcome => HistoryMsg::CycleOutcome,
+                Action::Export => HistoryMsg::Export,
+                Action::Copy => HistoryMsg::CopyLog,
+                _ => return None,
+            };
+            Some(Message::HistoryMsg(message))
         }
+        KeyContext::LinkBudget => match action {
+            Action::Close => Some(Message::LinkMsg(LinkMsg::Close)),
+            _ => None,
+        },
+        KeyContext::Help => match action {
+            Action::Close => Some(Message::ToggleHelp),
+            Action::Up => Some(Message::HelpMsg(ListMovement::Up)),
+            Action::Down => Some(Message::HelpMsg(ListMovement::Down)),
+            _ => None,
+        },
+    }
+}
+
+fn base_message(action: Action) -> Option<Message> {
+    match action {
+        Action::Close => Some(Message::Close),
+        Action::Up => Some(Message::PassListMsg(ListMovement::Up)),
+        Action::Down => Some(Message::PassListMsg(ListMovement::Down)),
+        Action::Select => Some(Message::PassListMsg(ListMovement::Select)),
+        Action::Satellites => Some(Message::ToggleSatConfig),
+        Action::Stations => Some(Message::ToggleGSConfig),
+        Action::Schedule => Some(Message::ToggleSchedule),
+        Action::Conjunctions => Some(Message::ToggleConjunctions),
+        Action::Elements => Some(Message::ToggleElements),
+        Action::Alerts => Some(Message::AlertMsg(AlertMsg::Toggle)),
+        Action::EventLog => Some(Message::ToggleEventLog),
+        Action::History => Some(Message::ToggleHistory),
+        Action::LinkBudget => Some(Message::LinkMsg(LinkMsg::Open)),
+        Action::ScrubBack => Some(Message::ClockMsg(ClockMsg::Scrub(-60))),
+        Action::ScrubForward => Some(Message::ClockMsg(ClockMsg::Scrub(60))),
+        Action::ScrubBackHour => Some(Message::ClockMsg(ClockMsg::Scrub(-3600))),
+        Action::ScrubForwardHour => Some(Message::ClockMsg(ClockMsg::Scrub(3600))),
+        Action::Faster => Some(Message::ClockMsg(ClockMsg::Faster)),
+        Action::Slower => Some(Message::ClockMsg(ClockMsg::Slower)),
+        Action::Pause => Some(Message::ClockMsg(ClockMsg::TogglePause)),
+        Action::NextAos => Some(Message::ClockMsg(ClockMsg::JumpNextAOS)),
+        Action::RealTime => Some(Message::ClockMsg(ClockMsg::RealTime)),
+        Action::Footprint => Some(Message::MapMsg(MapMsg::ToggleFootprint)),
+        Action::FootprintElevation => Some(Message::MapMsg(MapMsg::CycleFootprintElevation)),
+        Action::StationRings => Some(Message::MapMsg(MapMsg::ToggleStationRings)),
+        Action::Terminator => Some(Message::MapMsg(MapMsg::ToggleTerminator)),
+        Action::Twilight => Some(Message::MapMsg(MapMsg::ToggleTwilight)),
+        Action::ZoomIn => Some(Message::MapMsg(MapMsg::ZoomIn)),
+        Action::ZoomOut => Some(Message::MapMsg(MapMsg::ZoomOut)),
+        Action::PanLeft => Some(Message::MapMsg(MapMsg::Pan(-1.0, 0.0))),
+        Action::PanRight => Some(Message::MapMsg(MapMsg::Pan(1.0, 0.0))),
+        Action::PanUp => Some(Message::MapMsg(MapMsg::Pan(0.0, 1.0))),
+        Action::PanDown => Some(Message::MapMsg(MapMsg::Pan(0.0, -1.0))),
+        Action::MeridianWest => Some(Message::MapMsg(MapMsg::ShiftMeridian(-15.0))),
+        Action::MeridianEast => Some(Message::MapMsg(MapMsg::ShiftMeridian(15.0))),
+        Action::Projection => Some(Message::MapMsg(MapMsg::CycleProjection)),
+        Action::Follow => Some(Message::MapMsg(MapMsg::CycleFollow)),
+        Action::FocusStation => Some(Message::MapMsg(MapMsg::CycleFocusStation)),
+        Action::ResetView => Some(Message::MapMsg(MapMsg::ResetView)),
+        _ => None,
     }
 }
@@ -0,0 +1,33 @@
+use tracing::info;
+
+use crate::link_budget::margin_curve;
+use crate::structs::{AppState, LinkMsg, Message, Model};
+
+pub fn parse_link_msg(model: &mut Model, link_msg: LinkMsg) -> Option<Message> {
+    match link_msg {
+        LinkMsg::Open => {
+            let pass = if model.current_state == AppState::PassDetail {
+                model.pass_detail.pass.clone()
+            } else {
+                model
+                    .upcoming_passes
+                    .get(model.pass_detail.selected)
+                    .cloned()
+            };
+            if let (Some(satellite), Some(pass)) = (model.current_satellite.as_ref(), pass) {
+                info!("Opening link budget");
+                model.link.curve = margin_curve(&satellite.satellite, &pass, &model.link.settings);
+                model.link.pass = Some(pass);
+                model.link.return_state = model.current_state;
+                model.current_state = AppState::LinkBudget;
+            }
+            None
+        }
+        LinkMsg::Close => {
+            model.current_state = model.link.return_state;
+            model.link.pass = None;
+            model.link.curve.clear();
+            None
+        }
+    }
+}
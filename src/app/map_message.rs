@@ -0,0 +1,100 @@
+use crate::geometry::wrap_longitude;
+use crate::structs::{MapFollow, MapMsg, Message, Model, Projection};
+
+/// Minimum elevations (deg) the footprint can be drawn for
+const FOOTPRINT_ELEVATIONS: [f64; 6] = [0.0, 5.0, 10.0, 15.0, 20.0, 30.0];
+
+const MAX_ZOOM: f64 = 64.0;
+
+pub fn parse_map_msg(model: &mut Model, map_msg: MapMsg) -> Option<Message> {
+    match map_msg {
+        MapMsg::ToggleFootprint => {
+            model.map.show_footprint = !model.map.show_footprint;
+            None
+        }
+        MapMsg::CycleFootprintElevation => {
+            model.map.footprint_elevation = *FOOTPRINT_ELEVATIONS
+                .iter()
+                .find(|x| **x > model.map.footprint_elevation)
+                .unwrap_or(&FOOTPRINT_ELEVATIONS[0]);
+            None
+        }
+        MapMsg::ToggleStationRings => {
+            model.map.show_station_rings = !model.map.show_station_rings;
+            None
+        }
+        MapMsg::ToggleTerminator => {
+            model.map.show_terminator = !model.map.show_terminator;
+            None
+        }
+        MapMsg::ToggleTwilight => {
+            model.map.show_twilight = !model.map.show_twilight;
+            None
+        }
+        MapMsg::ZoomIn => {
+            model.map.zoom = (model.map.zoom * 2.0).min(MAX_ZOOM);
+            None
+        }
+        MapMsg::ZoomOut => {
+            model.map.zoom = (model.map.zoom / 2.0).max(1.0);
+            None
+        }
+        MapMsg::Pan(x, y) => {
+            let vertical_step = match model.map.projection {
+                Projection::Equirectangular => 22.5,
+                Projection::AzimuthalEquidistant => 45.0,
+            };
+            model.map.pan.0 += x * 45.0 / model.map.zoom;
+            model.map.pan.1 += y * vertical_step / model.map.zoom;
+            None
+        }
+        MapMsg::ShiftMeridian(x) => {
+            model.map.central_meridian = wrap_longitude(model.map.central_meridian + x);
+            None
+        }
+        MapMsg::CycleProjection => {
+            model.map.projection = match model.map.projection {
+                Projection::Equirectangular => Projection::AzimuthalEquidistant,
+                Projection::AzimuthalEquidistant => Projection::Equirectangular,
+            };
+            model.map.pan = (0.0, 0.0);
+            None
+        }
+        MapMsg::CycleFollow => {
+            model.map.follow = match model.map.follow {
+                MapFollow::Free => MapFollow::Station,
+                MapFollow::Station => MapFollow::Satellite,
+                MapFollow::Satellite => MapFollow::Free,
+            };
+            model.map.pan = (0.0, 0.0);
+            None
+        }
+        MapMsg::CycleFocusStation => {
+            let active = model
+                .station_config
+                .station_list
+                .iter()
+                .filter(|x| x.active)
+                .count();
+            model.map.focus_station = if active == 0 {
+                0
+            } else {
+                (model.map.focus_station + 1) % active
+            };
+            None
+        }
+        MapMsg::ResetView => {
+            model.map.projection = Projection::Equirectangular;
+            model.map.follow = MapFollow::Free;
+            model.map.zoom = 1.0;
+            model.map.pan = (0.0, 0.0);
+            model.map.central_meridian = 0.0;
+            model.map.cursor = None;
+            None
+        }
+        MapMsg::Click(lat, long) => {
+            model.map.cursor = Some((lat, long));
+            None
+        }
+    }
+}
@@ -0,0 +1,146 @@
+#[cfg(not(target_arch = "wasm32"))]
+use ratatui::crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
+
+use super::key_handle::action_message;
+use crate::keymap::{Action, KeyContext};
+use crate::projection::MapProjection;
+use crate::structs::{
+    AppState, GSConfigMsg, GSconfigState, ListMovement, MapMsg, Message, Model, SatList,
+};
+
+/// A mouse event at a terminal cell, independent of the terminal or browser backend
+#[derive(Debug, Clone, Copy)]
+pub enum Mouse {
+    Click(u16, u16),
+    ScrollUp(u16, u16),
+    ScrollDown(u16, u16),
+}
+
+impl Mouse {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_event(event: &MouseEvent) -> Option<Mouse> {
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => Some(Mouse::Click(event.column, event.row)),
+            MouseEventKind::ScrollUp => Some(Mouse::ScrollUp(event.column, event.row)),
+            MouseEventKind::ScrollDown => Some(Mouse::ScrollDown(event.column, event.row)),
+            _ => None,
+        }
+    }
+
+    fn position(&self) -> Position {
+        match *self {
+            Mouse::Click(x, y) | Mouse::ScrollUp(x, y) | Mouse::ScrollDown(x, y) => {
+                Position::new(x, y)
+            }
+        }
+    }
+}
+
+/// The row index under the position, from the rows recorded while drawing
+fn row_at(rows: &[(Rect, usize)], position: Position) -> Option<usize> {
+    rows.iter().find(|x| x.0.contains(position)).map(|x| x.1)
+}
+
+pub fn handle_mouse(model: &Model, mouse: Mouse) -> Option<Message> {
+    let layout = model.layout.borrow();
+    let position = mouse.position();
+    match (model.current_state, mouse) {
+        (AppState::Base, Mouse::Click(x, y)) => {
+            if let Some(pass) = row_at(&layout.passes, position) {
+                return Some(Message::PassListMsg(ListMovement::Click(pass)));
+            }
+            let (lat, long) =
+                MapProjection::new(model, layout.map).cell_position(layout.map, x, y)?;
+            Some(Message::MapMsg(MapMsg::Click(lat, long)))
+        }
+        (AppState::Base, Mouse::ScrollUp(..)) if layout.map.contains(position) => {
+            Some(Message::MapMsg(MapMsg::ZoomIn))
+        }
+        (AppState::Base, Mouse::ScrollDown(..)) if layout.map.contains(position) => {
+            Some(Message::MapMsg(MapMsg::ZoomOut))
+        }
+        (AppState::SatSelect, Mouse::Click(..)) if !model.sat_config.groups.editing => {
+            let row = row_at(&layout.satellites, position)?;
+            Some(Message::SatListMessage(SatList::ListMovement(
+                ListMovement::Click(row),
+            )))
+        }
+        (AppState::GSConfig, Mouse::Click(..))
+            if model.station_config.editing == GSconfigState::RowSelect =>
+        {
+            let row = row_at(&layout.stations, position)?;
+            Some(Message::GSConfigMsg(GSConfigMsg::ListMovement(
+                ListMovement::Click(row),
+            )))
+        }
+        // Anywhere else the wheel scrolls whatever list the arrow keys would
+        (_, Mouse::ScrollUp(..)) => action_message(KeyContext::of(model)?, Action::Up),
+        (_, Mouse::ScrollDown(..)) => action_message(KeyContext::of(model)?, Action::Down),
+        _ => None,
+    }
+}
+
+/// Browser mouse events are in pixels, so they are scaled to cells
+#[cfg(target_arch = "wasm32")]
+pub fn handle_mouse_event(
+    model: &Model,
+    mouse_event: ratzilla::event::MouseEvent,
+    tx: std::sync::mpsc::Sender<Message>,
+) {
+    use ratzilla::event::{MouseButton, MouseEventKind};
+
+    if !matches!(
+        (mouse_event.event, mouse_event.button),
+        (MouseEventKind::Pressed, MouseButton::Left)
+    ) {
+        return;
+    }
+    let Some((column, row)) = web_cell(model, f64::from(mouse_event.x), f64::from(mouse_event.y))
+    else {
+        return;
+    };
+    if let Some(x) = handle_mouse(model, Mouse::Click(column, row)) {
+        tx.send(x).unwrap()
+    }
+}
+
+/// ratzilla does not report the wheel, so it is listened for on the page directly
+#[cfg(target_arch = "wasm32")]
+pub fn handle_wheel_event(
+    model: &Model,
+    wheel_event: web_sys::WheelEvent,
+    tx: std::sync::mpsc::Sender<Message>,
+) {
+    let Some((column, row)) = web_cell(
+        model,
+        f64::from(wheel_event.client_x()),
+        f64::from(wheel_event.client_y()),
+    ) else {
+        return;
+    };
+    let mouse = if wheel_event.delta_y() < 0.0 {
+        Mouse::ScrollUp(column, row)
+    } else {
+        Mouse::ScrollDown(column, row)
+    };
+    if let Some(x) = handle_mouse(model, mouse) {
+        tx.send(x).unwrap()
+    }
+}
+
+/// The cell under a position in pixels, scaled by the window size and the size of the last frame drawn
+#[cfg(target_arch = "wasm32")]
+fn web_cell(model: &Model, x: f64, y: f64) -> Option<(u16, u16)> {
+    let window = web_sys::window()?;
+    let screen = model.layout.borrow().screen;
+    let size = |x: Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue>| {
+        x.ok().and_then(|y| y.as_f64()).filter(|y| *y > 0.0)
+    };
+    let width = size(window.inner_width())?;
+    let height = size(window.inner_height())?;
+    Some((
+        (x / width * f64::from(screen.width)) as u16,
+        (y / height * f64::from(screen.height)) as u16,
+    ))
+}
@@ -0,0 +1,142 @@
+use tracing::{info, warn};
+
+use crate::pass_plan::{plan_pass, plan_to_csv};
+use crate::structs::{
+    AppState, CurrentMsg, ListMovement, Message, Model, PLAN_STEPS, PassDetailMsg,
+};
+
+pub fn parse_pass_list_msg(model: &mut Model, list_movement: ListMovement) -> Option<Message> {
+    let selection = &mut model.pass_detail.selected;
+    match list_movement {
+        ListMovement::Up => {
+            *selection = selection.saturating_sub(1);
+            None
+        }
+        ListMovement::Down => {
+            *selection = (*selection + 1).min(model.upcoming_passes.len().saturating_sub(1));
+            None
+        }
+        ListMovement::Select => model
+            .upcoming_passes
+            .get(*selection)
+            .map(|x| Message::PassDetailMsg(PassDetailMsg::Open(x.clone()))),
+        ListMovement::Click(x) => {
+            *selection = x.min(model.upcoming_passes.len().saturating_sub(1));
+            Some(Message::PassListMsg(ListMovement::Select))
+        }
+        _ => None,
+    }
+}
+
+pub fn parse_pass_detail_msg(model: &mut Model, pass_detail_msg: PassDetailMsg) -> Option<Message> {
+    match pass_detail_msg {
+        PassDetailMsg::Open(pass) => {
+            model.current_satellite.as_ref()?;
+            info!("Opening pass detail for {}", pass.pass.get_aos_datetime());
+            model.pass_detail.return_state = model.current_state;
+            model.pass_detail.pass = Some(pass);
+            model.pass_detail.current_message = CurrentMsg::message("");
+            model.current_state = AppState::PassDetail;
+            update_plan(model);
+            None
+        }
+        PassDetailMsg::Close => {
+            model.current_state = model.pass_detail.return_state;
+            model.pass_detail.pass = None;
+            model.pass_detail.plan = vec![];
+            None
+        }
+        PassDetailMsg::ListMovement(list_movement) => {
+            match list_movement {
+                ListMovement::Up => model.pass_detail.table_state.scroll_up_by(1),
+                ListMovement::Down => {
+                    let last = model.pass_detail.plan.len().saturating_sub(1);
+                    let selection = model
+                        .pass_detail
+                        .table_state
+                        .selected()
+                        .map_or(0, |x| (x + 1).min(last));
+                    model.pass_detail.table_state.select(Some(selection));
+                }
+                _ => {}
+            }
+            None
+        }
+        PassDetailMsg::CycleStep => {
+            model.pass_detail.step_index = (model.pass_detail.step_index + 1) % PLAN_STEPS.len();
+            update_plan(model);
+            None
+        }
+        PassDetailMsg::Export => {
+            export_plan(model);
+            None
+        }
+        PassDetailMsg::CopyTable => {
+            if let Some(csv) = plan_csv(model) {
+                match model.sat_config.clipboard.set_text(&csv) {
+                    Ok(_) => {
+                        model.pass_detail.current_message =
+                            CurrentMsg::message("Copied pass plan to clipboard")
+                    }
+                    Err(_) => {
+                        model.pass_detail.current_message =
+                            CurrentMsg::error("Failed to copy to clipboard!")
+                    }
+                }
+            }
+            None
+        }
+    }
+}
+
+/// Recomputes the pass plan at the current step and resets the table to the top
+fn update_plan(model: &mut Model) {
+    if let (Some(satellite), Some(pass)) = (
+        model.current_satellite.as_ref(),
+        model.pass_detail.pass.as_ref(),
+    ) {
+        model.pass_detail.plan = plan_pass(&satellite.satellite, pass, model.pass_detail.step());
+        model.pass_detail.table_state.select(Some(0));
+    }
+}
+
+fn plan_csv(model: &Model) -> Option<String> {
+    let satellite = model.current_satellite.as_ref()?;
+    let pass = model.pass_detail.pass.as_ref()?;
+    Some(plan_to_csv(
+        &satellite.satellite,
+        pass,
+        &model.pass_detail.plan,
+    ))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn export_plan(model: &mut Model) {
+    use super::file_cache::cache::export_file;
+    use crate::pass_plan::plan_file_name;
+
+    let (Some(satellite), Some(pass), Some(csv)) = (
+        model.current_satellite.as_ref(),
+        model.pass_detail.pass.as_ref(),
+        plan_csv(model),
+    ) else {
+        return;
+    };
+    match export_file(&plan_file_name(&satellite.satellite, pass), &csv) {
+        Ok(path) => {
+            model.pass_detail.current_message =
+                CurrentMsg::message(&format!("Exported to {}", path.display()))
+        }
+        Err(x) => {
+            warn!("{}", x);
+            model.pass_detail.current_message = CurrentMsg::error("Failed to export pass plan");
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn export_plan(model: &mut Model) {
+    warn!("File export is unavailable in the browser");
+    model.pass_detail.current_message =
+        CurrentMsg::error("Export to file is unavailable in the browser, copy the table instead");
+}
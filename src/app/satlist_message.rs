@@ -1,13 +1,31 @@
-use crate::structs::{AppState, CurrentMsg, ListMovement, Message, Model, SatList};
+use std::collections::HashMap;
+
+use chrono::TimeDelta;
+#[cfg(not(target_arch = "wasm32"))]
+use ratatui::crossterm::event::KeyCode;
+#[cfg(target_arch = "wasm32")]
+use ratzilla::event::KeyCode;
+use sky_track::{GroundStation, find_passes_datetime};
+
+use tracing::warn;
+
+use crate::app::file_cache::cache::cache_sat_tags;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::sat_groups::GroupFetch;
+use crate::sat_groups::{SatGroup, group_tles};
+use crate::sat_search::SatSort;
+use crate::structs::{
+    AppState, CurrentMsg, ListMovement, Message, Model, SatEntry, SatList, TLSatellite,
+};
 #[cfg(target_arch = "wasm32")]
 use std::sync::mpsc::Sender;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub fn parse_satlist_msg(model: &mut Model, x: SatList) -> Option<Message> {
     use sky_track::Satellite;
-    use tracing::{info, warn};
+    use tracing::info;
 
-    use crate::{app::file_cache::cache::cache_tle, structs::TLSatellite};
+    use crate::{app::file_cache::cache::cache_tle, tle::validate_tle};
 
     use super::file_cache::cache::get_tle_spacetrack;
 
@@ -27,79 +45,182 @@ pub fn parse_satlist_msg(model: &mut Model, x: SatList) -> Option<Message> {
                 model.sat_config.list_state.scroll_down_by(1);
                 None
             }
-            ListMovement::Select => {
-                if let Some(index) = model.sat_config.list_state.selected() {
-                    if index == model.sat_config.satellite_list.len() {
-                        return Some(Message::SatListMessage(SatList::AddSatellite));
-                    } else if let Some(x) = model.sat_config.satellite_list.get(index) {
-                        model.current_satellite = Some(x.clone());
-                        return Some(Message::ToggleSatConfig);
-                    };
-                };
+            ListMovement::Click(x) => {
+                if model.sat_config.list_state.selected() == Some(x) {
+                    return Some(Message::SatListMessage(SatList::ListMovement(
+                        ListMovement::Select,
+                    )));
+                }
+                model.sat_config.list_state.select(Some(x));
                 None
             }
+            ListMovement::Select => match model.sat_config.selected()? {
+                SatEntry::AddSatellite => Some(Message::SatListMessage(SatList::AddSatellite)),
+                SatEntry::Group(x) => track_group(model, x),
+                SatEntry::Satellite(x) => {
+                    model.current_satellite = Some(model.sat_config.satellite_list[x].clone());
+                    model.target = None;
+                    model.tracked_group = None;
+                    Some(Message::ToggleSatConfig)
+                }
+                SatEntry::Body(x) => {
+                    model.current_satellite = None;
+                    model.target = Some(x);
+                    model.tracked_group = None;
+                    Some(Message::ToggleSatConfig)
+                }
+            },
             _ => None,
         },
+        SatList::StartSearch
+        | SatList::StopSearch
+        | SatList::ClearSearch
+        | SatList::SearchTyped(_)
+        | SatList::SearchBackspace
+        | SatList::CycleSort => parse_search_msg(model, x),
+        SatList::ToggleFavourite
+        | SatList::EditGroups
+        | SatList::GroupTyped(_)
+        | SatList::GroupBackspace
+        | SatList::SaveGroups
+        | SatList::CancelGroups => parse_group_msg(model, x),
+        SatList::ExportGroup => {
+            export_group(model);
+            None
+        }
         SatList::CopyTLE => {
-            if let Some(index) = model.sat_config.list_state.selected() {
-                if let Some(x) = model.sat_config.satellite_list.get(index) {
-                    match model.sat_config.clipboard.set_text(x.satellite.get_tle()) {
-                        Ok(_) => {
-                            model.sat_config.current_message =
-                                CurrentMsg::message("Copied TLE to clipboard")
-                        }
-                        Err(_) => {
-                            model.sat_config.current_message =
-                                CurrentMsg::error("Failed to copy to clipboard!");
-                        }
+            if let Some((_, x)) = model.sat_config.selected_satellite() {
+                let tle = x.satellite.get_tle().to_string();
+                match model.sat_config.clipboard.set_text(&tle) {
+                    Ok(_) => {
+                        model.sat_config.current_message =
+                            CurrentMsg::message("Copied TLE to clipboard")
+                    }
+                    Err(_) => {
+                        model.sat_config.current_message =
+                            CurrentMsg::error("Failed to copy to clipboard!");
                     }
                 }
             }
             None
         }
         SatList::FetchTLE => {
-            if let Some(index) = model.sat_config.list_state.selected() {
-                if let Some(x) = model.sat_config.satellite_list.get(index) {
-                    let new_tle = get_tle_spacetrack(x.satellite.get_norad_id());
-                    match new_tle {
-                        Ok(y) => {
-                            info!("Got TLE from celestrak: {}", y.as_str());
-                            let satellite = Satellite::new_from_tle(y.as_str());
-                            model.sat_config.satellite_list[index] = TLSatellite {
-                                satellite,
-                                metadata: x.metadata.clone(),
-                            };
-                            if let Err(x) = cache_tle(&model.sat_config.satellite_list) {
-                                warn!("{}", x);
-                                model.sat_config.current_message =
-                                    CurrentMsg::error("Failed to cache TLE");
-                            }
-                            model.current_satellite = None;
-                            model.sat_config.current_message = CurrentMsg::message(&format!(
-                                "Updated TLE for satellite: {}",
-                                model.sat_config.satellite_list[index].satellite.get_name()
-                            ));
-                        }
-                        Err(x) => {
-                            warn! {"{}",x};
+            if let Some((group, members)) = model.sat_config.selected_group() {
+                if model.sat_config.groups.fetching {
+                    return None;
+                }
+                let norad_ids: Vec<u64> = members
+                    .iter()
+                    .map(|x| model.sat_config.satellite_list[*x].satellite.get_norad_id())
+                    .collect();
+                model.sat_config.groups.fetching = true;
+                model.sat_config.current_message = CurrentMsg::message(&format!(
+                    "Fetching {} TLEs in {}…",
+                    norad_ids.len(),
+                    group.name()
+                ));
+                let sender = model.sat_config.groups.sender.clone();
+                let group = group.name().to_string();
+                std::thread::spawn(move || {
+                    let _ = sender.send(fetch_group(group, norad_ids));
+                });
+            } else if let Some((index, x)) = model.sat_config.selected_satellite() {
+                let new_tle = get_tle_spacetrack(x.satellite.get_norad_id());
+                match new_tle {
+                    Ok(y) if validate_tle(&y).is_empty() => {
+                        info!("Got TLE from celestrak: {}", y.as_str());
+                        let satellite = Satellite::new_from_tle(y.as_str());
+                        model.sat_config.satellite_list[index] = TLSatellite {
+                            satellite,
+                            metadata: x.metadata.clone(),
+                        };
+                        if let Err(x) = cache_tle(&model.sat_config.satellite_list) {
+                            warn!("{}", x);
                             model.sat_config.current_message =
-                                CurrentMsg::error("Failed to collect TLE from celestrak");
+                                CurrentMsg::error("Failed to cache TLE");
                         }
+                        model.current_satellite = None;
+                        model.sat_config.current_message = CurrentMsg::message(&format!(
+                            "Updated TLE for satellite: {}",
+                            model.sat_config.satellite_list[index].satellite.get_name()
+                        ));
                     }
+                    Ok(y) => {
+                        warn!("Celestrak returned an invalid TLE: {:?}", validate_tle(&y));
+                        model.sat_config.current_message =
+                            CurrentMsg::error("Celestrak returned an invalid TLE");
+                    }
+                    Err(x) => {
+                        warn! {"{}",x};
+                        model.sat_config.current_message =
+                            CurrentMsg::error("Failed to collect TLE from celestrak");
+                    }
+                }
+            }
+            None
+        }
+        SatList::GroupFetched(fetch) => {
+            model.sat_config.groups.fetching = false;
+            for (norad_id, tle) in &fetch.tles {
+                if let Some(x) = model
+                    .sat_config
+                    .satellite_list
+                    .iter_mut()
+                    .find(|x| x.satellite.get_norad_id() == *norad_id)
+                {
+                    x.satellite = Satellite::new_from_tle(tle);
                 }
             }
+            model.current_satellite = None;
+            model.sat_config.current_message = if fetch.tles.len() < fetch.requested {
+                CurrentMsg::error(&format!(
+                    "Updated {} of {} TLEs in {}",
+                    fetch.tles.len(),
+                    fetch.requested,
+                    fetch.group
+                ))
+            } else {
+                CurrentMsg::message(&format!(
+                    "Updated {} TLEs in {}",
+                    fetch.requested, fetch.group
+                ))
+            };
+            if let Err(x) = cache_tle(&model.sat_config.satellite_list) {
+                warn!("{}", x);
+                model.sat_config.current_message = CurrentMsg::error("Failed to cache TLE");
+            }
             None
         }
     }
 }
+
+/// Fetches each of the group's TLEs from celestrak in turn, keeping the ones that validate
+#[cfg(not(target_arch = "wasm32"))]
+fn fetch_group(group: String, norad_ids: Vec<u64>) -> GroupFetch {
+    use super::file_cache::cache::get_tle_spacetrack;
+    use crate::tle::validate_tle;
+
+    let mut tles = vec![];
+    for i in norad_ids.iter().copied() {
+        match get_tle_spacetrack(i) {
+            Ok(x) if validate_tle(&x).is_empty() => tles.push((i, x)),
+            Ok(_) => warn!("Celestrak returned an invalid TLE for {}", i),
+            Err(x) => warn!("{}", x),
+        }
+    }
+    GroupFetch {
+        group,
+        tles,
+        requested: norad_ids.len(),
+    }
+}
+
 #[cfg(target_arch = "wasm32")]
 pub fn parse_satlist_msg(
     model: &mut Model,
     x: SatList,
     tx_channel: Sender<Message>,
 ) -> Option<Message> {
-    use ehttp::Request;
-
     match x {
         SatList::AddSatellite => {
             model.current_state = AppState::SatAddition;
@@ -116,76 +237,292 @@ pub fn parse_satlist_msg(
                 model.sat_config.list_state.scroll_down_by(1);
                 None
             }
-            ListMovement::Select => {
-                if let Some(index) = model.sat_config.list_state.selected() {
-                    if index == model.sat_config.satellite_list.len() {
-                        return Some(Message::SatListMessage(SatList::AddSatellite));
-                    } else if let Some(x) = model.sat_config.satellite_list.get(index) {
-                        model.current_satellite = Some(x.clone());
-                        return Some(Message::ToggleSatConfig);
-                    };
-                };
+            ListMovement::Click(x) => {
+                if model.sat_config.list_state.selected() == Some(x) {
+                    return Some(Message::SatListMessage(SatList::ListMovement(
+                        ListMovement::Select,
+                    )));
+                }
+                model.sat_config.list_state.select(Some(x));
                 None
             }
+            ListMovement::Select => match model.sat_config.selected()? {
+                SatEntry::AddSatellite => Some(Message::SatListMessage(SatList::AddSatellite)),
+                SatEntry::Group(x) => track_group(model, x),
+                SatEntry::Satellite(x) => {
+                    model.current_satellite = Some(model.sat_config.satellite_list[x].clone());
+                    model.target = None;
+                    model.tracked_group = None;
+                    Some(Message::ToggleSatConfig)
+                }
+                SatEntry::Body(x) => {
+                    model.current_satellite = None;
+                    model.target = Some(x);
+                    model.tracked_group = None;
+                    Some(Message::ToggleSatConfig)
+                }
+            },
             _ => None,
         },
+        SatList::StartSearch
+        | SatList::StopSearch
+        | SatList::ClearSearch
+        | SatList::SearchTyped(_)
+        | SatList::SearchBackspace
+        | SatList::CycleSort => parse_search_msg(model, x),
+        SatList::ToggleFavourite
+        | SatList::EditGroups
+        | SatList::GroupTyped(_)
+        | SatList::GroupBackspace
+        | SatList::SaveGroups
+        | SatList::CancelGroups => parse_group_msg(model, x),
+        SatList::ExportGroup => {
+            export_group(model);
+            None
+        }
         SatList::CopyTLE => {
-            if let Some(index) = model.sat_config.list_state.selected() {
-                if let Some(x) = model.sat_config.satellite_list.get(index) {
-                    match model.sat_config.clipboard.set_text(x.satellite.get_tle()) {
-                        Ok(_) => {
-                            model.sat_config.current_message =
-                                CurrentMsg::message("Copied TLE to clipboard")
-                        }
-                        Err(_) => {
-                            model.sat_config.current_message =
-                                CurrentMsg::error("Failed to copy to clipboard!");
-                        }
+            if let Some((_, x)) = model.sat_config.selected_satellite() {
+                let tle = x.satellite.get_tle().to_string();
+                match model.sat_config.clipboard.set_text(&tle) {
+                    Ok(_) => {
+                        model.sat_config.current_message =
+                            CurrentMsg::message("Copied TLE to clipboard")
+                    }
+                    Err(_) => {
+                        model.sat_config.current_message =
+                            CurrentMsg::error("Failed to copy to clipboard!");
                     }
                 }
             }
             None
         }
         SatList::FetchTLE => {
-            if let Some(index) = model.sat_config.list_state.selected() {
-                if let Some(x) = model.sat_config.satellite_list.get(index) {
-                    let tle_sender = tx_channel.clone();
-                    let norad_id_tle = x.satellite.get_norad_id().to_string();
-                    ehttp::fetch(
-                        Request::get(format!(
-                            "https://celestrak.org/NORAD/elements/gp.php?CATNR={}&FORMAT=TLE",
-                            x.satellite.get_norad_id()
-                        )),
-                        move |x| {
-                            if let Ok(y) = x {
-                                if y.ok {
-                                    match y.text() {
-                                        Some(x) => {
-                                            tle_sender.send(Message::TLEResponse(
-                                                norad_id_tle,
-                                                x.to_string(),
-                                            ));
-                                        }
-                                        None => {
-                                            tle_sender.send(Message::FetchError(
-                                                "Could not parse TLE".to_string(),
-                                            ));
-                                        }
-                                    }
-                                } else {
-                                    tle_sender
-                                        .send(Message::FetchError("Could not get TLE".to_string()));
-                                }
-                            } else {
-                                tle_sender.send(Message::FetchError(
-                                    "Could not Access the network".to_string(),
-                                ));
-                            }
-                        },
-                    );
+            if let Some((group, members)) = model.sat_config.selected_group() {
+                for i in members.iter() {
+                    let norad_id = model.sat_config.satellite_list[*i].satellite.get_norad_id();
+                    fetch_tle(norad_id, tx_channel.clone());
                 }
+                model.sat_config.current_message = CurrentMsg::message(&format!(
+                    "Fetching {} TLEs in {}",
+                    members.len(),
+                    group.name()
+                ));
+            } else if let Some((_, x)) = model.sat_config.selected_satellite() {
+                fetch_tle(x.satellite.get_norad_id(), tx_channel.clone());
             }
             None
         }
     }
 }
+
+#[cfg(target_arch = "wasm32")]
+fn fetch_tle(norad_id: u64, tle_sender: Sender<Message>) {
+    use ehttp::Request;
+
+    ehttp::fetch(
+        Request::get(format!(
+            "https://celestrak.org/NORAD/elements/gp.php?CATNR={}&FORMAT=TLE",
+            norad_id
+        )),
+        move |x| {
+            if let Ok(y) = x {
+                if y.ok {
+                    match y.text() {
+                        Some(x) => {
+                            tle_sender
+                                .send(Message::TLEResponse(norad_id.to_string(), x.to_string()));
+                        }
+                        None => {
+                            tle_sender.send(Message::FetchError("Could not parse TLE".to_string()));
+                        }
+                    }
+                } else {
+                    tle_sender.send(Message::FetchError("Could not get TLE".to_string()));
+                }
+            } else {
+                tle_sender.send(Message::FetchError(
+                    "Could not Access the network".to_string(),
+                ));
+            }
+        },
+    );
+}
+
+/// Tracks the group's first member (or keeps the current satellite if it is in the group) and draws the rest on the map
+fn track_group(model: &mut Model, group: SatGroup) -> Option<Message> {
+    let members = model
+        .sat_config
+        .groups
+        .tags
+        .members(&group, &model.sat_config.satellite_list);
+    let first = *members.first()?;
+    let current_in_group = model.current_satellite.as_ref().is_some_and(|x| {
+        model
+            .sat_config
+            .groups
+            .tags
+            .contains(&group, x.satellite.get_norad_id())
+    });
+    if !current_in_group {
+        model.current_satellite = Some(model.sat_config.satellite_list[first].clone());
+    }
+    model.target = None;
+    model.tracked_group = Some(group);
+    Some(Message::ToggleSatConfig)
+}
+
+fn parse_group_msg(model: &mut Model, x: SatList) -> Option<Message> {
+    let selected = model
+        .sat_config
+        .selected_satellite()
+        .map(|(index, x)| (index, x.satellite.get_norad_id()));
+    let groups = &mut model.sat_config.groups;
+    match x {
+        SatList::ToggleFavourite => {
+            let (index, norad_id) = selected?;
+            groups.tags.toggle_favourite(norad_id);
+            save_groups(model);
+            select_satellite(model, index);
+        }
+        SatList::EditGroups => {
+            let (_, norad_id) = selected?;
+            groups.buffer = groups.tags.groups_of(norad_id).join(", ");
+            groups.editing = true;
+        }
+        SatList::GroupTyped(KeyCode::Char(x)) => groups.buffer.push(x),
+        SatList::GroupBackspace => {
+            groups.buffer.pop();
+        }
+        SatList::SaveGroups => {
+            groups.editing = false;
+            let (index, norad_id) = selected?;
+            let names: Vec<String> = groups
+                .buffer
+                .split(',')
+                .map(|x| x.trim().to_string())
+                .filter(|x| !x.is_empty())
+                .collect();
+            groups.tags.set_groups(norad_id, &names);
+            save_groups(model);
+            select_satellite(model, index);
+        }
+        SatList::CancelGroups => groups.editing = false,
+        _ => {}
+    }
+    None
+}
+
+fn save_groups(model: &mut Model) {
+    if let Err(x) = cache_sat_tags(&model.sat_config.groups.tags) {
+        warn!("{}", x);
+        model.sat_config.current_message = CurrentMsg::error("Unable to save satellite groups");
+    }
+}
+
+/// Keeps the satellite selected after regrouping moves its row
+fn select_satellite(model: &mut Model, index: usize) {
+    let row = model
+        .sat_config
+        .entries()
+        .iter()
+        .position(|x| *x == SatEntry::Satellite(index));
+    if row.is_some() {
+        model.sat_config.list_state.select(row);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn export_group(model: &mut Model) {
+    use super::file_cache::cache::export_file;
+
+    let Some((group, members)) = model.sat_config.selected_group() else {
+        model.sat_config.current_message = CurrentMsg::error("Select a group header to export");
+        return;
+    };
+    let satellites: Vec<&TLSatellite> = members
+        .iter()
+        .map(|x| &model.sat_config.satellite_list[*x])
+        .collect();
+    let filename = format!("{}.tle", group.name().replace(['/', '\\', ' '], "_"));
+    model.sat_config.current_message = match export_file(&filename, &group_tles(&satellites)) {
+        Ok(path) => CurrentMsg::message(&format!("Exported to {}", path.display())),
+        Err(x) => {
+            warn!("{}", x);
+            CurrentMsg::error("Failed to export group")
+        }
+    };
+}
+
+/// The browser can't write files, so the group's TLEs go to the clipboard instead
+#[cfg(target_arch = "wasm32")]
+fn export_group(model: &mut Model) {
+    let Some((group, members)) = model.sat_config.selected_group() else {
+        model.sat_config.current_message = CurrentMsg::error("Select a group header to export");
+        return;
+    };
+    let satellites: Vec<&TLSatellite> = members
+        .iter()
+        .map(|x| &model.sat_config.satellite_list[*x])
+        .collect();
+    let tles = group_tles(&satellites);
+    model.sat_config.current_message = match model.sat_config.clipboard.set_text(&tles) {
+        Ok(_) => CurrentMsg::message(&format!("Copied {} TLEs to clipboard", group.name())),
+        Err(_) => CurrentMsg::error("Failed to copy to clipboard!"),
+    };
+}
+
+fn parse_search_msg(model: &mut Model, x: SatList) -> Option<Message> {
+    let search = &mut model.sat_config.search;
+    match x {
+        SatList::StartSearch => search.editing = true,
+        SatList::StopSearch => search.editing = false,
+        SatList::ClearSearch => {
+            search.editing = false;
+            search.query.clear();
+        }
+        SatList::SearchTyped(KeyCode::Char(x)) => search.query.push(x),
+        SatList::SearchBackspace => {
+            search.query.pop();
+        }
+        SatList::CycleSort => {
+            search.sort = search.sort.next();
+            if search.sort == SatSort::NextPass {
+                let passes = next_passes(model);
+                model.sat_config.search.next_passes = passes;
+            }
+            model.sat_config.current_message = CurrentMsg::message(&format!(
+                "Sorted by {}",
+                model.sat_config.search.sort.name()
+            ));
+        }
+        _ => {}
+    }
+    model.sat_config.list_state.select(Some(0));
+    None
+}
+
+/// Next AOS of every cached satellite over the active stations, by NORAD ID
+fn next_passes(model: &Model) -> HashMap<u64, i64> {
+    let now = model.clock.now();
+    let end = now + TimeDelta::days(3);
+    let stations: Vec<&GroundStation> = model
+        .station_config
+        .station_list
+        .iter()
+        .filter(|x| x.active)
+        .map(|x| &x.station)
+        .collect();
+    model
+        .sat_config
+        .satellite_list
+        .iter()
+        .filter_map(|x| {
+            stations
+                .iter()
+                .flat_map(|y| find_passes_datetime(&x.satellite, y, &now, &end))
+                .map(|y| y.get_aos())
+                .min()
+                .map(|y| (x.satellite.get_norad_id(), y))
+        })
+        .collect()
+}
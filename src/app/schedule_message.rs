@@ -0,0 +1,85 @@
+use crate::schedule::{MIN_DURATIONS, MIN_ELEVATIONS, TIME_OF_DAY_WINDOWS};
+use crate::structs::{ListMovement, Message, Model, PassDetailMsg, ScheduleMsg};
+
+pub fn parse_schedule_msg(model: &mut Model, schedule_msg: ScheduleMsg) -> Option<Message> {
+    let schedule = &mut model.schedule;
+    match schedule_msg {
+        ScheduleMsg::ListMovement(list_movement) => match list_movement {
+            ListMovement::Up => {
+                schedule.table_state.scroll_up_by(1);
+                None
+            }
+            ListMovement::Down => {
+                let last = schedule
+                    .passes(&model.upcoming_passes)
+                    .len()
+                    .saturating_sub(1);
+                let selection = schedule
+                    .table_state
+                    .selected()
+                    .map_or(0, |x| (x + 1).min(last));
+                schedule.table_state.select(Some(selection));
+                None
+            }
+            ListMovement::Select => {
+                let index = schedule.table_state.selected()?;
+                schedule
+                    .passes(&model.upcoming_passes)
+                    .get(index)
+                    .map(|x| Message::PassDetailMsg(PassDetailMsg::Open((*x).clone())))
+            }
+            _ => None,
+        },
+        ScheduleMsg::CycleSort => {
+            schedule.sort = schedule.sort.next();
+            schedule.table_state.select(Some(0));
+            None
+        }
+        ScheduleMsg::ReverseSort => {
+            schedule.descending = !schedule.descending;
+            schedule.table_state.select(Some(0));
+            None
+        }
+        ScheduleMsg::CycleStation => {
+            let stations: Vec<&String> = model
+                .station_config
+                .station_list
+                .iter()
+                .filter(|x| x.active)
+                .map(|x| &x.station.name)
+                .collect();
+            let next = match &schedule.filter.station {
+                None => stations.first(),
+                Some(current) => stations
+                    .iter()
+                    .position(|x| *x == current)
+                    .and_then(|x| stations.get(x + 1)),
+            };
+            schedule.filter.station = next.map(|x| x.to_string());
+            schedule.table_state.select(Some(0));
+            None
+        }
+        ScheduleMsg::CycleMinElevation => {
+            schedule.filter.min_elevation =
+                (schedule.filter.min_elevation + 1) % MIN_ELEVATIONS.len();
+            schedule.table_state.select(Some(0));
+            None
+        }
+        ScheduleMsg::CycleMinDuration => {
+            schedule.filter.min_duration = (schedule.filter.min_duration + 1) % MIN_DURATIONS.len();
+            schedule.table_state.select(Some(0));
+            None
+        }
+        ScheduleMsg::CycleTimeOfDay => {
+            schedule.filter.time_of_day =
+                (schedule.filter.time_of_day + 1) % TIME_OF_DAY_WINDOWS.len();
+            schedule.table_state.select(Some(0));
+            None
+        }
+        ScheduleMsg::ClearFilters => {
+            schedule.filter = Default::default();
+            schedule.table_state.select(Some(0));
+            None
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use chrono::{DateTime, Utc};
+
+use crate::geometry::{EARTH_RADIUS, wrap_longitude};
+
+/// Kilometres in an astronomical unit
+const AU: f64 = 149_597_870.7;
+
+/// Apparent geocentric position of a body, angles in degrees and distance in km
+#[derive(Debug, Clone, Copy)]
+pub struct CelestialPosition {
+    pub right_ascension: f64,
+    pub declination: f64,
+    pub distance: f64,
+}
+
+/// Bodies that can be tracked in place of a satellite, e.g. for sun noise measurements
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CelestialBody {
+    Sun,
+    Moon,
+}
+
+/// Bodies listed alongside the satellites, in order
+pub const CELESTIAL_BODIES: [CelestialBody; 2] = [CelestialBody::Sun, CelestialBody::Moon];
+
+impl CelestialBody {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CelestialBody::Sun => "Sun",
+            CelestialBody::Moon => "Moon",
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            CelestialBody::Sun => "☼",
+            CelestialBody::Moon => "☾",
+        }
+    }
+
+    pub fn position(&self, time: &DateTime<Utc>) -> CelestialPosition {
+        match self {
+            CelestialBody::Sun => sun_position(time),
+            CelestialBody::Moon => moon_position(time),
+        }
+    }
+}
+
+/// Pointing from an observer to a body
+#[derive(Debug, Clone, Copy)]
+pub struct Pointing {
+    pub azimuth: f64,
+    pub elevation: f64,
+    pub range: f64,
+}
+
+/// Fractional days since the J2000.0 epoch
+fn days_since_j2000(time: &DateTime<Utc>) -> f64 {
+    time.timestamp_millis() as f64 / 86_400_000.0 + 2_440_587.5 - 2_451_545.0
+}
+
+/// Mean obliquity of the ecliptic in degrees
+fn obliquity(days: f64) -> f64 {
+    23.439 - 0.000_000_4 * days
+}
+
+/// Greenwich mean sidereal time in degrees
+pub fn gmst(time: &DateTime<Utc>) -> f64 {
+    (280.460_618_37 + 360.985_647_366_29 * days_since_j2000(time)).rem_euclid(360.0)
+}
+
+/// Low precision (~0.01 deg) solar position, from the Astronomical Almanac approximation
+pub fn sun_position(time: &DateTime<Utc>) -> CelestialPosition {
+    let days = days_since_j2000(time);
+    let mean_long = (280.460 + 0.985_647_4 * days).rem_euclid(360.0);
+    let mean_anomaly = (357.528 + 0.985_600_3 * days)
+        .rem_euclid(360.0)
+        .to_radians();
+    let ecliptic_long =
+        (mean_long + 1.915 * mean_anomaly.sin() + 0.020 * (2.0 * mean_anomaly).sin()).to_radians();
+    let obliquity = obliquity(days).to_radians();
+    CelestialPosition {
+        right_ascension: (obliquity.cos() * ecliptic_long.sin())
+            .atan2(ecliptic_long.cos())
+            .to_degrees()
+            .rem_euclid(360.0),
+        declination: (obliquity.sin() * ecliptic_long.sin()).asin().to_degrees(),
+        distance: (1.000_14
+            - 0.016_71 * mean_anomaly.cos()
+            - 0.000_14 * (2.0 * mean_anomaly).cos())
+            * AU,
+    }
+}
+
+/// Low precision (~0.3 deg) lunar position, from the Astronomical Almanac approximation
+pub fn moon_position(time: &DateTime<Utc>) -> CelestialPosition {
+    let days = days_since_j2000(time);
+    let centuries = days / 36525.0;
+    let sin = |a: f64, b: f64| (a + b * centuries).to_radians().sin();
+    let cos = |a: f64, b: f64| (a + b * centuries).to_radians().cos();
+    let ecliptic_long = (218.32 + 481_267.881 * centuries + 6.29 * sin(135.0, 477_198.87)
+        - 1.27 * sin(259.3, -413_335.36)
+        + 0.66 * sin(235.7, 890_534.22)
+        + 0.21 * sin(269.9, 954_397.74)
+        - 0.19 * sin(357.5, 35_999.05)
+        - 0.11 * sin(186.5, 966_404.03))
+    .to_radians();
+    let ecliptic_lat = (5.13 * sin(93.3, 483_202.02) + 0.28 * sin(228.2, 960_400.89)
+        - 0.28 * sin(318.3, 6_003.15)
+        - 0.17 * sin(217.6, -407_332.21))
+    .to_radians();
+    let parallax = (0.9508
+        + 0.0518 * cos(135.0, 477_198.87)
+        + 0.0095 * cos(259.3, -413_335.36)
+        + 0.0078 * cos(235.7, 890_534.22)
+        + 0.0028 * cos(269.9, 954_397.74))
+    .to_radians();
+    let obliquity = obliquity(days).to_radians();
+    let l = ecliptic_lat.cos() * ecliptic_long.cos();
+    let m = obliquity.cos() * ecliptic_lat.cos() * ecliptic_long.sin()
+        - obliquity.sin() * ecliptic_lat.sin();
+    let n = obliquity.sin() * ecliptic_lat.cos() * ecliptic_long.sin()
+        + obliquity.cos() * ecliptic_lat.sin();
+    CelestialPosition {
+        right_ascension: m.atan2(l).to_degrees().rem_euclid(360.0),
+        declination: n.clamp(-1.0, 1.0).asin().to_degrees(),
+        distance: EARTH_RADIUS / parallax.sin(),
+    }
+}
+
+/// Topocentric pointing to a body from (`lat`, `long`) deg at `alt` km, treating the earth as a sphere
+pub fn look_angle(
+    position: &CelestialPosition,
+    time: &DateTime<Utc>,
+    lat: f64,
+    long: f64,
+    alt: f64,
+) -> Pointing {
+    let hour_angle = (position.right_ascension - gmst(time)).to_radians();
+    let declination = position.declination.to_radians();
+    let body = [
+        position.distance * declination.cos() * hour_angle.cos(),
+        position.distance * declination.cos() * hour_angle.sin(),
+        position.distance * declination.sin(),
+    ];
+    let (lat, long) = (lat.to_radians(), long.to_radians());
+    let radius = EARTH_RADIUS + alt;
+    let observer = [
+        radius * lat.cos() * long.cos(),
+        radius * lat.cos() * long.sin(),
+        radius * lat.sin(),
+    ];
+    let [x, y, z] = [
+        body[0] - observer[0],
+        body[1] - observer[1],
+        body[2] - observer[2],
+    ];
+    let east = -long.sin() * x + long.cos() * y;
+    let north = -lat.sin() * long.cos() * x - lat.sin() * long.sin() * y + lat.cos() * z;
+    let up = lat.cos() * long.cos() * x + lat.cos() * long.sin() * y + lat.sin() * z;
+    let range = (x * x + y * y + z * z).sqrt();
+    Pointing {
+        azimuth: east.atan2(north).to_degrees().rem_euclid(360.0),
+        elevation: (up / range).asin().to_degrees(),
+        range,
+    }
+}
+
+/// Point on the earth with the body at the zenith, as (lat, long)
+pub fn sub_point(position: &CelestialPosition, time: &DateTime<Utc>) -> (f64, f64) {
+    (
+        position.declination,
+        wrap_longitude(position.right_ascension - gmst(time)),
+    )
+}
+
+/// Angle (deg) between two directions given as (azimuth, elevation) in degrees
+pub fn angular_separation(a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (az_a, el_a) = (a.0.to_radians(), a.1.to_radians());
+    let (az_b, el_b) = (b.0.to_radians(), b.1.to_radians());
+    (el_a.sin() * el_b.sin() + el_a.cos() * el_b.cos() * (az_a - az_b).cos())
+        .clamp(-1.0, 1.0)
+        .acos()
+        .to_degrees()
+}
@@ -0,0 +1,148 @@
+use chrono::{DateTime, TimeDelta, Utc};
+
+/// Rates the clock steps through when speeding up or slowing down, in simulated seconds per real second
+const RATE_STEPS: [f64; 11] = [
+    -600.0, -60.0, -10.0, -1.0, 0.0, 1.0, 10.0, 60.0, 600.0, 3600.0, 86400.0,
+];
+
+/// Offsets shorter than this (ms) are treated as real time, so speeding up and slowing down again returns to it
+const REAL_TIME_TOLERANCE: i64 = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClockMode {
+    RealTime,
+    Fixed(DateTime<Utc>),
+    Offset(TimeDelta),
+    Scaled {
+        anchor_real: DateTime<Utc>,
+        anchor_sim: DateTime<Utc>,
+        rate: f64,
+    },
+}
+
+/// Time source used by every view and pass propagation, so passes can be rehearsed or replayed
+#[derive(Debug, Clone)]
+pub struct Clock {
+    mode: ClockMode,
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Clock {
+            mode: ClockMode::RealTime,
+        }
+    }
+}
+
+impl Clock {
+    pub fn now(&self) -> DateTime<Utc> {
+        match self.mode {
+            ClockMode::RealTime => Utc::now(),
+            ClockMode::Fixed(x) => x,
+            ClockMode::Offset(x) => Utc::now() + x,
+            ClockMode::Scaled {
+                anchor_real,
+                anchor_sim,
+                rate,
+            } => {
+                let elapsed = Utc::now().signed_duration_since(anchor_real);
+                anchor_sim
+                    + TimeDelta::milliseconds((elapsed.num_milliseconds() as f64 * rate) as i64)
+            }
+        }
+    }
+
+    pub fn is_real_time(&self) -> bool {
+        self.mode == ClockMode::RealTime
+    }
+
+    pub fn rate(&self) -> f64 {
+        match self.mode {
+            ClockMode::RealTime | ClockMode::Offset(_) => 1.0,
+            ClockMode::Fixed(_) => 0.0,
+            ClockMode::Scaled { rate, .. } => rate,
+        }
+    }
+
+    pub fn set_rate(&mut self, rate: f64) {
+        let now = self.now();
+        self.mode = if rate == 0.0 {
+            ClockMode::Fixed(now)
+        } else if rate == 1.0 {
+            offset(now.signed_duration_since(Utc::now()))
+        } else {
+            ClockMode::Scaled {
+                anchor_real: Utc::now(),
+                anchor_sim: now,
+                rate,
+            }
+        };
+    }
+
+    pub fn faster(&mut self) {
+        let current = self.rate();
+        if let Some(x) = RATE_STEPS.iter().find(|x| **x > current) {
+            self.set_rate(*x)
+        }
+    }
+
+    pub fn slower(&mut self) {
+        let current = self.rate();
+        if let Some(x) = RATE_STEPS.iter().rev().find(|x| **x < current) {
+            self.set_rate(*x)
+        }
+    }
+
+    pub fn toggle_pause(&mut self) {
+        if self.rate() == 0.0 {
+            self.set_rate(1.0)
+        } else {
+            self.set_rate(0.0)
+        }
+    }
+
+    /// Moves the simulated time by `delta` while keeping the current rate
+    pub fn shift(&mut self, delta: TimeDelta) {
+        self.mode = match self.mode {
+            ClockMode::RealTime => offset(delta),
+            ClockMode::Fixed(x) => ClockMode::Fixed(x + delta),
+            ClockMode::Offset(x) => offset(x + delta),
+            ClockMode::Scaled {
+                anchor_real,
+                anchor_sim,
+                rate,
+            } => ClockMode::Scaled {
+                anchor_real,
+                anchor_sim: anchor_sim + delta,
+                rate,
+            },
+        }
+    }
+
+    pub fn jump_to(&mut self, time: DateTime<Utc>) {
+        self.shift(time.signed_duration_since(self.now()))
+    }
+
+    pub fn reset(&mut self) {
+        self.mode = ClockMode::RealTime
+    }
+
+    /// Short description of the clock rate for the status indicator
+    pub fn describe(&self) -> String {
+        match self.mode {
+            ClockMode::RealTime => "REAL TIME".to_string(),
+            ClockMode::Fixed(_) => "PAUSED".to_string(),
+            ClockMode::Offset(_) => "x1".to_string(),
+            ClockMode::Scaled { rate, .. } => format!("x{}", rate),
+        }
+    }
+}
+
+/// Running at real rate `delta` from now, which is real time if the offset is negligible
+fn offset(delta: TimeDelta) -> ClockMode {
+    if delta.abs() < TimeDelta::milliseconds(REAL_TIME_TOLERANCE) {
+        ClockMode::RealTime
+    } else {
+        ClockMode::Offset(delta)
+    }
+}
@@ -0,0 +1,353 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc::{Receiver, Sender, channel};
+
+use chrono::{DateTime, Utc};
+use ratatui::widgets::TableState;
+use sky_track::Satellite;
+use tracing::warn;
+
+use crate::geometry::EARTH_RADIUS;
+use crate::structs::{CurrentMsg, TLSatellite};
+
+/// Screening windows (hours) cycled through in the conjunction view
+pub const SCREENING_WINDOWS: [i64; 4] = [6, 24, 72, 168];
+
+/// Miss distance thresholds (km) cycled through in the conjunction view
+pub const MISS_THRESHOLDS: [f64; 6] = [1.0, 5.0, 10.0, 25.0, 50.0, 100.0];
+
+/// WGS84 flattening
+const FLATTENING: f64 = 1.0 / 298.257_223_563;
+
+/// Seconds between samples in the coarse search
+const COARSE_STEP: i64 = 60;
+
+/// A close approach between two satellites
+#[derive(Debug, Clone)]
+pub struct Conjunction {
+    pub primary: String,
+    pub secondary: String,
+    pub time: DateTime<Utc>,
+    /// km
+    pub miss_distance: f64,
+    /// km/s
+    pub relative_velocity: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConjunctionSort {
+    Time,
+    MissDistance,
+    RelativeVelocity,
+    Primary,
+}
+
+impl ConjunctionSort {
+    pub fn next(self) -> ConjunctionSort {
+        match self {
+            ConjunctionSort::Time => ConjunctionSort::MissDistance,
+            ConjunctionSort::MissDistance => ConjunctionSort::RelativeVelocity,
+            ConjunctionSort::RelativeVelocity => ConjunctionSort::Primary,
+            ConjunctionSort::Primary => ConjunctionSort::Time,
+        }
+    }
+}
+
+/// Outcome of one screening run
+#[derive(Debug, Clone)]
+pub struct Screening {
+    pub results: Vec<Conjunction>,
+    pub satellites: usize,
+    /// km
+    pub threshold: f64,
+}
+
+/// State of the conjunction screening screen. Natively the screening runs on its own thread and reports back over the channel.
+pub struct Conjunctions {
+    pub results: Vec<Conjunction>,
+    pub running: bool,
+    pub table_state: TableState,
+    pub sort: ConjunctionSort,
+    pub descending: bool,
+    pub window: usize,
+    pub threshold: usize,
+    pub current_message: CurrentMsg,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub sender: Sender<Screening>,
+    #[cfg(not(target_arch = "wasm32"))]
+    receiver: Receiver<Screening>,
+}
+
+impl Default for Conjunctions {
+    fn default() -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let (sender, receiver) = channel();
+        Conjunctions {
+            results: vec![],
+            running: false,
+            table_state: TableState::default().with_selected(Some(0)),
+            sort: ConjunctionSort::Time,
+            descending: false,
+            window: 1,
+            threshold: 3,
+            current_message: CurrentMsg::message("Press <Enter> to run the screening"),
+            #[cfg(not(target_arch = "wasm32"))]
+            sender,
+            #[cfg(not(target_arch = "wasm32"))]
+            receiver,
+        }
+    }
+}
+
+impl Conjunctions {
+    /// The result of the screening running in the background, once it has finished
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn finished(&self) -> Option<Screening> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// Screening window in seconds
+    pub fn window(&self) -> i64 {
+        SCREENING_WINDOWS[self.window % SCREENING_WINDOWS.len()] * 3600
+    }
+
+    pub fn threshold(&self) -> f64 {
+        MISS_THRESHOLDS[self.threshold % MISS_THRESHOLDS.len()]
+    }
+
+    /// The results in the selected order
+    pub fn sorted(&self) -> Vec<&Conjunction> {
+        let mut output: Vec<&Conjunction> = self.results.iter().collect();
+        output.sort_by(|a, b| {
+            let order = match self.sort {
+                ConjunctionSort::Time => a.time.cmp(&b.time),
+                ConjunctionSort::MissDistance => a.miss_distance.total_cmp(&b.miss_distance),
+                ConjunctionSort::RelativeVelocity => {
+                    a.relative_velocity.total_cmp(&b.relative_velocity)
+                }
+                ConjunctionSort::Primary => a.primary.cmp(&b.primary),
+            };
+            if self.descending {
+                order.reverse()
+            } else {
+                order
+            }
+        });
+        output
+    }
+}
+
+type Vector = [f64; 3];
+
+fn subtract(a: Vector, b: Vector) -> Vector {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: Vector, b: Vector) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn norm(a: Vector) -> f64 {
+    dot(a, a).sqrt()
+}
+
+/// Earth fixed position (km) of the satellite `offset` seconds after its epoch
+fn position(satellite: &Satellite, offset: i64) -> Vector {
+    let sub_point = satellite.get_sub_point(offset);
+    let (lat, long) = (sub_point.lat.to_radians(), sub_point.long.to_radians());
+    let eccentricity_sq = FLATTENING * (2.0 - FLATTENING);
+    let normal = EARTH_RADIUS / (1.0 - eccentricity_sq * lat.sin().powi(2)).sqrt();
+    [
+        (normal + sub_point.alt) * lat.cos() * long.cos(),
+        (normal + sub_point.alt) * lat.cos() * long.sin(),
+        (normal * (1.0 - eccentricity_sq) + sub_point.alt) * lat.sin(),
+    ]
+}
+
+/// Relative position of `b` from `a` at unix time `time`
+fn relative_position(a: &Satellite, b: &Satellite, time: i64) -> Vector {
+    subtract(
+        position(b, time - b.get_epoch().timestamp()),
+        position(a, time - a.get_epoch().timestamp()),
+    )
+}
+
+/// Refines a coarse minimum at `time` to the second, then interpolates the relative motion linearly within it
+fn refine(a: &Satellite, b: &Satellite, mut time: i64) -> (f64, f64, f64) {
+    let mut step = COARSE_STEP / 2;
+    while step > 0 {
+        time = [time - step, time, time + step]
+            .into_iter()
+            .min_by(|x, y| {
+                norm(relative_position(a, b, *x)).total_cmp(&norm(relative_position(a, b, *y)))
+            })
+            .unwrap_or(time);
+        step /= 2;
+    }
+    let centre = relative_position(a, b, time);
+    let velocity = subtract(
+        relative_position(a, b, time + 1),
+        relative_position(a, b, time - 1),
+    )
+    .map(|x| x / 2.0);
+    let speed = norm(velocity);
+    let offset = if speed > 0.0 {
+        (-dot(centre, velocity) / (speed * speed)).clamp(-1.0, 1.0)
+    } else {
+        0.0
+    };
+    let closest = [
+        centre[0] + velocity[0] * offset,
+        centre[1] + velocity[1] * offset,
+        centre[2] + velocity[2] * offset,
+    ];
+    (time as f64 + offset, norm(closest), speed)
+}
+
+/// Whether the altitude shells of two orbits come within `threshold` km of each other
+fn shells_overlap(a: &Satellite, b: &Satellite, threshold: f64) -> bool {
+    let (apogee_a, perigee_a) = a.get_apogee_perigee();
+    let (apogee_b, perigee_b) = b.get_apogee_perigee();
+    perigee_a.max(perigee_b) - apogee_a.min(apogee_b) <= threshold
+}
+
+/// Screens one pair over the sampled tracks, returning each approach under the threshold
+fn screen_pair(
+    a: &TLSatellite,
+    b: &TLSatellite,
+    tracks: (&[Vector], &[Vector]),
+    start: i64,
+    threshold: f64,
+) -> Vec<Conjunction> {
+    let distances: Vec<f64> = tracks
+        .0
+        .iter()
+        .zip(tracks.1)
+        .map(|(x, y)| norm(subtract(*x, *y)))
+        .collect();
+    let mut output = vec![];
+    for i in 1..distances.len().saturating_sub(1) {
+        if distances[i] > distances[i - 1] || distances[i] > distances[i + 1] {
+            continue;
+        }
+        // The true minimum can sit up to half a step either side, so allow for the closing speed
+        let closing = (distances[i - 1] - distances[i]).max(distances[i + 1] - distances[i]);
+        if distances[i] - closing > threshold {
+            continue;
+        }
+        let (time, miss_distance, relative_velocity) =
+            refine(&a.satellite, &b.satellite, start + i as i64 * COARSE_STEP);
+        if miss_distance <= threshold {
+            output.push(Conjunction {
+                primary: a.satellite.get_name(),
+                secondary: b.satellite.get_name(),
+                time: DateTime::from_timestamp_millis((time * 1000.0) as i64).unwrap_or_default(),
+                miss_distance,
+                relative_velocity,
+            });
+        }
+    }
+    output
+}
+
+/// Screens the given pairs of satellites
+fn screen_pairs(
+    satellites: &[TLSatellite],
+    tracks: &[Vec<Vector>],
+    pairs: &[(usize, usize)],
+    start: i64,
+    threshold: f64,
+) -> Vec<Conjunction> {
+    pairs
+        .iter()
+        .flat_map(|(i, j)| {
+            screen_pair(
+                &satellites[*i],
+                &satellites[*j],
+                (&tracks[*i], &tracks[*j]),
+                start,
+                threshold,
+            )
+        })
+        .collect()
+}
+
+/// Finds close approaches between every pair of satellites over `window` seconds from `start`.
+/// Pairs whose altitude shells never meet are skipped, the rest are sampled coarsely and refined around each local minimum.
+pub fn screen(
+    satellites: &[TLSatellite],
+    start: DateTime<Utc>,
+    window: i64,
+    threshold: f64,
+) -> Screening {
+    let start = start.timestamp();
+    let times: Vec<i64> = (start..=start + window)
+        .step_by(COARSE_STEP as usize)
+        .collect();
+    let pairs: Vec<(usize, usize)> = (0..satellites.len())
+        .flat_map(|i| (i + 1..satellites.len()).map(move |j| (i, j)))
+        .filter(|(i, j)| {
+            shells_overlap(
+                &satellites[*i].satellite,
+                &satellites[*j].satellite,
+                threshold,
+            )
+        })
+        .collect();
+    let tracks: Vec<Vec<Vector>> = in_parallel(satellites, |chunk| {
+        chunk
+            .iter()
+            .map(|x| {
+                let epoch = x.satellite.get_epoch().timestamp();
+                times
+                    .iter()
+                    .map(|y| position(&x.satellite, y - epoch))
+                    .collect()
+            })
+            .collect()
+    });
+    let results = if tracks.len() == satellites.len() {
+        in_parallel(&pairs, |chunk| {
+            screen_pairs(satellites, &tracks, chunk, start, threshold)
+        })
+    } else {
+        warn!("Propagation failed for some satellites, skipping the screening");
+        vec![]
+    };
+    Screening {
+        results,
+        satellites: satellites.len(),
+        threshold,
+    }
+}
+
+/// Runs `work` over `items` split into one chunk per CPU core, joining the results in order
+#[cfg(not(target_arch = "wasm32"))]
+fn in_parallel<T, R, F>(items: &[T], work: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&[T]) -> Vec<R> + Sync,
+{
+    let threads = std::thread::available_parallelism().map_or(1, |x| x.get());
+    let chunk = items.len().div_ceil(threads).max(1);
+    let work = &work;
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk)
+            .map(|x| scope.spawn(move || work(x)))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|x| x.join().unwrap_or_default())
+            .collect()
+    })
+}
+
+/// The browser build has no threads, so works through the items on the main thread
+#[cfg(target_arch = "wasm32")]
+fn in_parallel<T, R, F>(items: &[T], work: F) -> Vec<R>
+where
+    F: Fn(&[T]) -> Vec<R>,
+{
+    work(items)
+}
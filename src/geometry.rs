@@ -0,0 +1,120 @@
+/// Equatorial radius of the earth in km
+pub const EARTH_RADIUS: f64 = 6378.137;
+
+/// Angular radius (deg) of the region on the ground from which an object at `altitude` km is seen above `min_elevation` deg
+pub fn footprint_radius(altitude: f64, min_elevation: f64) -> f64 {
+    let elevation = min_elevation.to_radians();
+    let ratio = EARTH_RADIUS / (EARTH_RADIUS + altitude.max(0.0));
+    ((ratio * elevation.cos()).acos() - elevation)
+        .to_degrees()
+        .max(0.0)
+}
+
+/// Point reached from (`lat`, `long`) after travelling `distance` deg of arc along `bearing` deg, as (long, lat)
+pub fn destination_point(lat: f64, long: f64, bearing: f64, distance: f64) -> (f64, f64) {
+    let lat_1 = lat.to_radians();
+    let long_1 = long.to_radians();
+    let bearing = bearing.to_radians();
+    let distance = distance.to_radians();
+    let lat_2 = (lat_1.sin() * distance.cos() + lat_1.cos() * distance.sin() * bearing.cos())
+        .clamp(-1.0, 1.0)
+        .asin();
+    let long_2 = long_1
+        + (bearing.sin() * distance.sin() * lat_1.cos())
+            .atan2(distance.cos() - lat_1.sin() * lat_2.sin());
+    (wrap_longitude(long_2.to_degrees()), lat_2.to_degrees())
+}
+
+/// Normalises a longitude into [-180, 180)
+pub fn wrap_longitude(long: f64) -> f64 {
+    (long + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Closed small circle of angular `radius` deg around (`lat`, `long`), as (long, lat) points.
+///
+/// The points are not split at the antimeridian, that is left to the map projection.
+pub fn small_circle(lat: f64, long: f64, radius: f64, samples: usize) -> Vec<(f64, f64)> {
+    if radius <= 0.0 || samples < 3 {
+        return vec![];
+    }
+    (0..=samples)
+        .map(|x| destination_point(lat, long, 360.0 * x as f64 / samples as f64, radius))
+        .collect()
+}
+
+/// Great circle distance and initial bearing (both deg) from the first point to the second
+pub fn distance_bearing(lat_1: f64, long_1: f64, lat_2: f64, long_2: f64) -> (f64, f64) {
+    let (lat_1, lat_2) = (lat_1.to_radians(), lat_2.to_radians());
+    let delta_long = (long_2 - long_1).to_radians();
+    let distance = (lat_1.sin() * lat_2.sin() + lat_1.cos() * lat_2.cos() * delta_long.cos())
+        .clamp(-1.0, 1.0)
+        .acos();
+    let bearing = (delta_long.sin() * lat_2.cos())
+        .atan2(lat_1.cos() * lat_2.sin() - lat_1.sin() * lat_2.cos() * delta_long.cos());
+    (
+        distance.to_degrees(),
+        bearing.to_degrees().rem_euclid(360.0),
+    )
+}
+
+/// Makes a sequence of (long, lat) points continuous in longitude, so consecutive points never jump by more than 180 deg
+pub fn unwrap_longitudes(points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut output: Vec<(f64, f64)> = Vec::with_capacity(points.len());
+    for i in points {
+        match output.last() {
+            Some(prev) => {
+                let delta = wrap_longitude(i.0 - wrap_longitude(prev.0));
+                output.push((prev.0 + delta, i.1))
+            }
+            None => output.push(*i),
+        }
+    }
+    output
+}
+
+/// Part of a path drawn on the map, with the time (or other parameter) carried by each point
+#[derive(Debug, Clone, Default)]
+pub struct PathSegment {
+    pub points: Vec<(f64, f64)>,
+    pub times: Vec<f64>,
+}
+
+/// Splits a longitude-continuous path into map segments within [-180, 180], interpolating the crossing point (and its time) at the edge.
+///
+/// Circles enclosing a pole come out as a single curve spanning every longitude rather than a line across the map.
+pub fn split_antimeridian(points: &[(f64, f64)], times: &[f64]) -> Vec<PathSegment> {
+    let mut segments: Vec<PathSegment> = vec![];
+    let mut current = PathSegment::default();
+    let cell = |x: f64| ((x + 180.0) / 360.0).floor();
+    for (index, (point, time)) in points.iter().zip(times).enumerate() {
+        if index > 0 {
+            let (prev, prev_time) = (points[index - 1], times[index - 1]);
+            let (prev_cell, next_cell) = (cell(prev.0), cell(point.0));
+            if prev_cell != next_cell {
+                let boundary = -180.0 + 360.0 * prev_cell.max(next_cell);
+                let ratio = (boundary - prev.0) / (point.0 - prev.0);
+                let lat = prev.1 + ratio * (point.1 - prev.1);
+                let crossing_time = prev_time + ratio * (time - prev_time);
+                let (exit, entry) = if next_cell > prev_cell {
+                    (180.0, -180.0)
+                } else {
+                    (-180.0, 180.0)
+                };
+                current.points.push((exit, lat));
+                current.times.push(crossing_time);
+                segments.push(current);
+                current = PathSegment {
+                    points: vec![(entry, lat)],
+                    times: vec![crossing_time],
+                };
+            }
+        }
+        current
+            .points
+            .push((point.0 - 360.0 * cell(point.0), point.1));
+        current.times.push(*time);
+    }
+    segments.push(current);
+    segments.retain(|x| x.points.len() > 1);
+    segments
+}
@@ -0,0 +1,151 @@
+use sky_track::Satellite;
+use tracing::debug;
+
+use crate::geometry::{PathSegment, wrap_longitude};
+use crate::projection::{MapProjection, ProjectionKey};
+use crate::tle::Elements;
+
+/// Seconds of track drawn behind the satellite
+pub const TRACK_HISTORY: i64 = 300;
+
+/// Simulated seconds the clock can move either way before the cached track is recomputed
+const REFRESH_THRESHOLD: i64 = 30;
+
+/// Track samples per terminal cell of map width, at 1x zoom
+const SAMPLES_PER_CELL: f64 = 4.0;
+
+/// Coarsest sampling step in seconds, so short tracks on wide terminals still look smooth
+const MAX_STEP: i64 = 60;
+
+/// Seconds between time ticks along the track
+pub const TICK_INTERVAL: i64 = 600;
+
+/// A labelled point along the track
+#[derive(Debug, Clone, Copy)]
+pub struct TrackMarker {
+    pub time: i64,
+    pub lat: f64,
+    pub long: f64,
+    pub revolution: Option<u64>,
+}
+
+/// Sampling step (seconds) giving roughly a few points per braille dot for the visible part of the track
+pub fn sample_step(range: i64, width: u16, zoom: f64) -> i64 {
+    let samples = (f64::from(width) * SAMPLES_PER_CELL * zoom).max(1.0);
+    ((range + TRACK_HISTORY) as f64 / samples).clamp(1.0, MAX_STEP as f64) as i64
+}
+
+/// Ground track of the current satellite, only recomputed when the satellite, TLE, window or sampling change,
+/// or the clock has moved past the cached window. The antimeridian split is cached per projection.
+#[derive(Default)]
+pub struct TrackCache {
+    norad_id: u64,
+    tle: String,
+    range: i64,
+    step: i64,
+    anchor: i64,
+    points: Vec<(f64, f64)>,
+    times: Vec<f64>,
+    ticks: Vec<TrackMarker>,
+    nodes: Vec<TrackMarker>,
+    projection: Option<ProjectionKey>,
+    segments: Vec<PathSegment>,
+}
+
+impl TrackCache {
+    /// Brings the cached track up to date, recomputing only what has changed
+    pub fn update(
+        &mut self,
+        satellite: &Satellite,
+        now: i64,
+        range: i64,
+        step: i64,
+        projection: &MapProjection,
+    ) {
+        if self.norad_id != satellite.get_norad_id()
+            || !satellite.get_tle().eq(self.tle.as_str())
+            || self.range != range
+            || self.step != step
+            || (now - self.anchor).abs() >= REFRESH_THRESHOLD
+            || self.points.is_empty()
+        {
+            debug!("Recomputing ground track with a {step}s step");
+            let epoch = satellite.get_epoch().timestamp();
+            let times: Vec<i64> = (now - TRACK_HISTORY..now + range + REFRESH_THRESHOLD)
+                .step_by(step.max(1) as usize)
+                .collect();
+            self.points = times
+                .iter()
+                .map(|x| {
+                    let sub_point = satellite.get_sub_point(x - epoch);
+                    (sub_point.long, sub_point.lat)
+                })
+                .collect();
+            self.times = times.iter().map(|x| *x as f64).collect();
+            let first_tick =
+                (now - TRACK_HISTORY).div_euclid(TICK_INTERVAL) * TICK_INTERVAL + TICK_INTERVAL;
+            self.ticks = (first_tick..now + range + REFRESH_THRESHOLD)
+                .step_by(TICK_INTERVAL as usize)
+                .map(|x| {
+                    let sub_point = satellite.get_sub_point(x - epoch);
+                    TrackMarker {
+                        time: x,
+                        lat: sub_point.lat,
+                        long: sub_point.long,
+                        revolution: None,
+                    }
+                })
+                .collect();
+            self.nodes = ascending_nodes(&self.points, &times, epoch, satellite);
+            self.norad_id = satellite.get_norad_id();
+            self.tle = satellite.get_tle().to_string();
+            self.range = range;
+            self.step = step;
+            self.anchor = now;
+            self.projection = None;
+        }
+        if self.projection != Some(projection.key()) {
+            self.segments = projection.project_timed_path(&self.points, &self.times);
+            self.projection = Some(projection.key());
+        }
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    pub fn ticks(&self) -> &[TrackMarker] {
+        &self.ticks
+    }
+
+    pub fn nodes(&self) -> &[TrackMarker] {
+        &self.nodes
+    }
+}
+
+/// Ascending node crossings along the sampled track, interpolated between samples and labelled with the revolution number
+fn ascending_nodes(
+    points: &[(f64, f64)],
+    times: &[i64],
+    epoch: i64,
+    satellite: &Satellite,
+) -> Vec<TrackMarker> {
+    let elements = Elements::parse(satellite.get_tle()).ok();
+    let mut nodes = vec![];
+    for index in 1..points.len() {
+        let (prev, next) = (points[index - 1], points[index]);
+        if prev.1 < 0.0 && next.1 >= 0.0 {
+            let ratio = -prev.1 / (next.1 - prev.1);
+            let time = times[index - 1] as f64 + ratio * (times[index] - times[index - 1]) as f64;
+            nodes.push(TrackMarker {
+                time: time as i64,
+                lat: 0.0,
+                long: wrap_longitude(prev.0 + ratio * wrap_longitude(next.0 - prev.0)),
+                revolution: elements
+                    .as_ref()
+                    .map(|x| x.revolution_at(time - epoch as f64).round() as u64),
+            });
+        }
+    }
+    nodes
+}
@@ -0,0 +1,128 @@
+use chrono::{DateTime, Utc};
+use ratatui::widgets::TableState;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::app::file_cache::cache::get_history;
+use crate::structs::{CurrentMsg, TLPass};
+
+/// AOS times (seconds) closer than this are taken to be the same pass
+const SAME_PASS_TOLERANCE: i64 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum Outcome {
+    #[default]
+    Unknown,
+    Success,
+    Partial,
+    Missed,
+}
+
+impl Outcome {
+    pub fn next(self) -> Outcome {
+        match self {
+            Outcome::Unknown => Outcome::Success,
+            Outcome::Success => Outcome::Partial,
+            Outcome::Partial => Outcome::Missed,
+            Outcome::Missed => Outcome::Unknown,
+        }
+    }
+}
+
+/// A completed pass, as kept in `history.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub satellite: String,
+    pub norad_id: u64,
+    pub station: String,
+    pub aos: DateTime<Utc>,
+    pub los: DateTime<Utc>,
+    pub max_elevation: f64,
+    #[serde(default)]
+    pub outcome: Outcome,
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl HistoryEntry {
+    pub fn new(satellite: &str, pass: &TLPass) -> HistoryEntry {
+        HistoryEntry {
+            satellite: satellite.to_string(),
+            norad_id: pass.norad_id,
+            station: pass.station.name.clone(),
+            aos: pass.pass.get_aos_datetime(),
+            los: pass.pass.get_los_datetime(),
+            max_elevation: pass.pass.get_max_elevation(),
+            outcome: Outcome::Unknown,
+            notes: String::new(),
+        }
+    }
+
+    pub fn same_pass(&self, other: &HistoryEntry) -> bool {
+        self.norad_id == other.norad_id
+            && self.station == other.station
+            && self
+                .aos
+                .signed_duration_since(other.aos)
+                .num_seconds()
+                .abs()
+                < SAME_PASS_TOLERANCE
+    }
+}
+
+pub struct History {
+    pub entries: Vec<HistoryEntry>,
+    pub table_state: TableState,
+    pub editing: bool,
+    pub edit_buffer: String,
+    pub current_message: CurrentMsg,
+}
+
+impl Default for History {
+    fn default() -> Self {
+        let (entries, current_message) = match get_history() {
+            Ok(x) => (x, CurrentMsg::message("")),
+            Err(x) => {
+                warn!("Unable to load pass history: {}", x);
+                (vec![], CurrentMsg::error("Unable to load pass history"))
+            }
+        };
+        History {
+            entries,
+            table_state: TableState::default().with_selected(Some(0)),
+            editing: false,
+            edit_buffer: String::new(),
+            current_message,
+        }
+    }
+}
+
+/// Quotes a CSV field if it contains a separator, quote or newline
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// The pass history as CSV, for reporting
+pub fn history_to_csv(entries: &[HistoryEntry]) -> String {
+    let mut output = String::from(
+        "satellite,norad_id,station,aos_utc,los_utc,max_elevation_deg,outcome,notes\n",
+    );
+    for i in entries {
+        output.push_str(&format!(
+            "{},{},{},{},{},{:.1},{:?},{}\n",
+            csv_field(&i.satellite),
+            i.norad_id,
+            csv_field(&i.station),
+            i.aos.format("%Y-%m-%dT%H:%M:%SZ"),
+            i.los.format("%Y-%m-%dT%H:%M:%SZ"),
+            i.max_elevation,
+            i.outcome,
+            csv_field(&i.notes)
+        ));
+    }
+    output
+}
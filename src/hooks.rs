@@ -0,0 +1,155 @@
+use std::sync::mpsc::{Receiver, Sender, channel};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::alerts::PassEvent;
+use crate::app::file_cache::cache::get_hooks;
+use crate::structs::TLPass;
+
+/// Number of entries kept in the event log
+const LOG_LENGTH: usize = 200;
+
+fn default_lead_minutes() -> i64 {
+    5
+}
+
+/// A shell command run on a pass event, optionally only for one station and/or satellite
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Hook {
+    pub event: PassEvent,
+    pub command: String,
+    #[serde(default)]
+    pub station: Option<String>,
+    #[serde(default)]
+    pub norad_id: Option<u64>,
+    #[serde(default = "default_lead_minutes")]
+    pub lead_minutes: i64,
+    /// Also run while the clock is simulated, which is off so that scrubbing time does not start real equipment
+    #[serde(default)]
+    pub simulated: bool,
+}
+
+impl Hook {
+    pub fn applies_to(&self, pass: &TLPass, norad_id: u64) -> bool {
+        self.station
+            .as_ref()
+            .is_none_or(|x| *x == pass.station.name)
+            && self.norad_id.is_none_or(|x| x == norad_id)
+    }
+
+    /// Time the hook runs for the pass
+    pub fn time(&self, pass: &TLPass) -> DateTime<Utc> {
+        match self.event {
+            PassEvent::PreAos => {
+                pass.pass.get_aos_datetime() - TimeDelta::minutes(self.lead_minutes)
+            }
+            PassEvent::Aos => pass.pass.get_aos_datetime(),
+            PassEvent::Tme => pass.pass.get_tme_datetime(),
+            PassEvent::Los => pass.pass.get_los_datetime(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub time: DateTime<Utc>,
+    pub text: String,
+    pub error: bool,
+}
+
+/// Configured hooks and the log of what they did. Hooks run on their own thread and report back over the channel.
+pub struct Hooks {
+    pub hooks: Vec<Hook>,
+    pub log: Vec<LogEntry>,
+    pub sender: Sender<LogEntry>,
+    receiver: Receiver<LogEntry>,
+}
+
+impl Default for Hooks {
+    fn default() -> Self {
+        let (sender, receiver) = channel();
+        let mut log = vec![];
+        let hooks = match get_hooks() {
+            Ok(x) => x,
+            Err(x) => {
+                warn!("Unable to load hooks: {}", x);
+                log.push(LogEntry {
+                    time: Utc::now(),
+                    text: format!("Unable to load hooks: {}", x),
+                    error: true,
+                });
+                vec![]
+            }
+        };
+        Hooks {
+            hooks,
+            log,
+            sender,
+            receiver,
+        }
+    }
+}
+
+impl Hooks {
+    pub fn push(&mut self, entry: LogEntry) {
+        self.log.push(entry);
+        if self.log.len() > LOG_LENGTH {
+            self.log.remove(0);
+        }
+    }
+
+    /// Moves results reported by finished hooks into the log
+    pub fn collect(&mut self) {
+        while let Ok(x) = self.receiver.try_recv() {
+            self.push(x);
+        }
+    }
+}
+
+/// Runs `command` in the system shell with `env` set, reporting its exit status on `sender` once it finishes.
+/// Output is discarded, apart from stderr when the command fails.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run_hook(command: String, env: Vec<(String, String)>, sender: Sender<LogEntry>) {
+    use std::process::{Command, Stdio};
+
+    std::thread::spawn(move || {
+        let mut shell = if cfg!(windows) {
+            let mut shell = Command::new("cmd");
+            shell.arg("/C");
+            shell
+        } else {
+            let mut shell = Command::new("sh");
+            shell.arg("-c");
+            shell
+        };
+        let output = shell
+            .arg(&command)
+            .envs(env)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .output();
+        let entry = match output {
+            Ok(x) => {
+                let stderr = String::from_utf8_lossy(&x.stderr).trim().to_string();
+                LogEntry {
+                    time: Utc::now(),
+                    text: if x.status.success() || stderr.is_empty() {
+                        format!("`{}` exited with {}", command, x.status)
+                    } else {
+                        format!("`{}` exited with {}: {}", command, x.status, stderr)
+                    },
+                    error: !x.status.success(),
+                }
+            }
+            Err(x) => LogEntry {
+                time: Utc::now(),
+                text: format!("`{}` failed to start: {}", command, x),
+                error: true,
+            },
+        };
+        let _ = sender.send(entry);
+    });
+}
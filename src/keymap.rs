@@ -0,0 +1,649 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+#[cfg(not(target_arch = "wasm32"))]
+use ratatui::crossterm::event::KeyCode;
+#[cfg(target_arch = "wasm32")]
+use ratzilla::event::KeyCode;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::app::file_cache::cache::get_keymap;
+use crate::structs::{AppState, GSconfigState, Model};
+
+/// A key that can be bound, independent of the terminal or browser backend
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Key {
+    Char(char),
+    Esc,
+    Enter,
+    Backspace,
+    Tab,
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Key {
+    pub fn from_code(code: &KeyCode) -> Option<Key> {
+        match code {
+            KeyCode::Char(x) => Some(Key::Char(*x)),
+            KeyCode::Esc => Some(Key::Esc),
+            KeyCode::Enter => Some(Key::Enter),
+            KeyCode::Backspace => Some(Key::Backspace),
+            KeyCode::Tab => Some(Key::Tab),
+            KeyCode::Up => Some(Key::Up),
+            KeyCode::Down => Some(Key::Down),
+            KeyCode::Left => Some(Key::Left),
+            KeyCode::Right => Some(Key::Right),
+            _ => None,
+        }
+    }
+
+    /// Parses a key as written in the keymap file: a single character, or a key name such as `Esc` or `Up`
+    pub fn parse(text: &str) -> Option<Key> {
+        let mut chars = text.chars();
+        if let (Some(x), None) = (chars.next(), chars.next()) {
+            return Some(Key::Char(x));
+        }
+        match text.to_lowercase().as_str() {
+            "esc" | "escape" => Some(Key::Esc),
+            "enter" | "return" => Some(Key::Enter),
+            "backspace" => Some(Key::Backspace),
+            "tab" => Some(Key::Tab),
+            "space" => Some(Key::Char(' ')),
+            "up" => Some(Key::Up),
+            "down" => Some(Key::Down),
+            "left" => Some(Key::Left),
+            "right" => Some(Key::Right),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Key::Char(' ') => write!(f, "Space"),
+            Key::Char(x) => write!(f, "{}", x),
+            Key::Esc => write!(f, "Esc"),
+            Key::Enter => write!(f, "Enter"),
+            Key::Backspace => write!(f, "Backspace"),
+            Key::Tab => write!(f, "Tab"),
+            Key::Up => write!(f, "Up"),
+            Key::Down => write!(f, "Down"),
+            Key::Left => write!(f, "Left"),
+            Key::Right => write!(f, "Right"),
+        }
+    }
+}
+
+/// A set of bindings in effect together, one per screen plus the ground station cell selection.
+/// `Global` bindings apply on every screen for keys the screen itself does not bind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyContext {
+    Global,
+    Base,
+    SatSelect,
+    SatAddition,
+    #[serde(rename = "gs_config")]
+    GSConfig,
+    #[serde(rename = "gs_cell_select")]
+    GSCellSelect,
+    PassDetail,
+    Schedule,
+    EventLog,
+    History,
+    LinkBudget,
+    Conjunctions,
+    Help,
+}
+
+impl KeyContext {
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyContext::Global => "Everywhere",
+            KeyContext::Base => "Main View",
+            KeyContext::SatSelect => "Satellite List",
+            KeyContext::SatAddition => "Add Satellite",
+            KeyContext::GSConfig => "Ground Stations",
+            KeyContext::GSCellSelect => "Ground Station Cells",
+            KeyContext::PassDetail => "Pass Detail",
+            KeyContext::Schedule => "Schedule",
+            KeyContext::EventLog => "Event Log",
+            KeyContext::History => "Pass History",
+            KeyContext::LinkBudget => "Link Budget",
+            KeyContext::Conjunctions => "Conjunctions",
+            KeyContext::Help => "Help",
+        }
+    }
+
+    /// The bindings in effect, or nothing while text is being typed or no keys are accepted
+    pub fn of(model: &Model) -> Option<KeyContext> {
+        match model.current_state {
+            AppState::Base => Some(KeyContext::Base),
+            AppState::SatSelect => {
+                let editing = model.sat_config.search.editing || model.sat_config.groups.editing;
+                (!editing).then_some(KeyContext::SatSelect)
+            }
+            AppState::SatAddition => {
+                (!model.sat_config.add_sat.editing).then_some(KeyContext::SatAddition)
+            }
+            AppState::GSConfig => match model.station_config.editing {
+                GSconfigState::RowSelect => Some(KeyContext::GSConfig),
+                GSconfigState::CellSelect => Some(KeyContext::GSCellSelect),
+                GSconfigState::CellEdit => None,
+            },
+            AppState::PassDetail => Some(KeyContext::PassDetail),
+            AppState::Schedule => Some(KeyContext::Schedule),
+            AppState::EventLog => Some(KeyContext::EventLog),
+            AppState::History => (!model.history.editing).then_some(KeyContext::History),
+            AppState::LinkBudget => Some(KeyContext::LinkBudget),
+            AppState::Conjunctions => Some(KeyContext::Conjunctions),
+            AppState::Help => Some(KeyContext::Help),
+            #[cfg(target_arch = "wasm32")]
+            AppState::SatWaitingFetch => None,
+        }
+    }
+}
+
+/// Something a key can be bound to. What it does depends on the context it is bound in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Close,
+    Up,
+    Down,
+    Left,
+    Right,
+    Select,
+    Edit,
+    Satellites,
+    Stations,
+    Schedule,
+    Conjunctions,
+    Elements,
+    Alerts,
+    EventLog,
+    History,
+    LinkBudget,
+    ScrubBack,
+    ScrubForward,
+    ScrubBackHour,
+    ScrubForwardHour,
+    Faster,
+    Slower,
+    Pause,
+    NextAos,
+    RealTime,
+    Footprint,
+    FootprintElevation,
+    StationRings,
+    Terminator,
+    Twilight,
+    ZoomIn,
+    ZoomOut,
+    PanLeft,
+    PanRight,
+    PanUp,
+    PanDown,
+    MeridianWest,
+    MeridianEast,
+    Projection,
+    Follow,
+    FocusStation,
+    ResetView,
+    Search,
+    Sort,
+    Reverse,
+    Copy,
+    Fetch,
+    Favourite,
+    Groups,
+    Export,
+    Paste,
+    Activate,
+    Step,
+    FilterStation,
+    FilterElevation,
+    FilterDuration,
+    FilterTimeOfDay,
+    ClearFilters,
+    Run,
+    Window,
+    Threshold,
+    Outcome,
+    Help,
+}
+
+impl Action {
+    /// What the action does in the context, as shown in the help popup
+    pub fn description(&self, context: KeyContext) -> &'static str {
+        match (self, context) {
+            (Action::Close, KeyContext::Base) => "Quit",
+            (Action::Close, KeyContext::GSCellSelect) => "Back to station selection",
+            (Action::Close, KeyContext::Help) => "Close help",
+            (Action::Close, _) => "Close popup",
+            (Action::Up, KeyContext::Base) => "Previous upcoming pass",
+            (Action::Down, KeyContext::Base) => "Next upcoming pass",
+            (Action::Up | Action::Down, KeyContext::SatAddition) => {
+                "Switch between NORAD ID and TLE"
+            }
+            (Action::Up, KeyContext::EventLog | KeyContext::Help) => "Scroll up",
+            (Action::Down, KeyContext::EventLog | KeyContext::Help) => "Scroll down",
+            (Action::Up, _) => "Move up",
+            (Action::Down, _) => "Move down",
+            (Action::Left, _) => "Previous cell",
+            (Action::Right, _) => "Next cell",
+            (Action::Select, KeyContext::Base | KeyContext::Schedule) => "Open pass detail",
+            (Action::Select, KeyContext::SatSelect) => {
+                "Track the selection (a whole group on its header)"
+            }
+            (Action::Select, KeyContext::GSConfig) => "Edit or add a station",
+            (Action::Select, _) => "Edit the cell",
+            (Action::Edit, KeyContext::History) => "Edit notes",
+            (Action::Edit, _) => "Start typing",
+            (Action::Satellites, _) => "Satellite list",
+            (Action::Stations, _) => "Ground stations",
+            (Action::Schedule, _) => "Pass schedule",
+            (Action::Conjunctions, _) => "Conjunction screening",
+            (Action::Elements, _) => "Toggle orbital elements",
+            (Action::Alerts, _) => "Toggle pass alerts",
+            (Action::EventLog, _) => "Event log",
+            (Action::History, _) => "Pass history",
+            (Action::LinkBudget, _) => "Link budget",
+            (Action::ScrubBack, _) => "Clock back 1 minute",
+            (Action::ScrubForward, _) => "Clock forward 1 minute",
+            (Action::ScrubBackHour, _) => "Clock back 1 hour",
+            (Action::ScrubForwardHour, _) => "Clock forward 1 hour",
+            (Action::Faster, _) => "Run the clock faster",
+            (Action::Slower, _) => "Run the clock slower",
+            (Action::Pause, _) => "Pause the clock",
+            (Action::NextAos, _) => "Jump to the next AOS",
+            (Action::RealTime, _) => "Back to real time",
+            (Action::Footprint, _) => "Toggle footprint",
+            (Action::FootprintElevation, _) => "Cycle footprint elevation",
+            (Action::StationRings, _) => "Toggle station rings",
+            (Action::Terminator, _) => "Toggle day/night terminator",
+            (Action::Twilight, _) => "Toggle twilight bands",
+            (Action::ZoomIn, _) => "Zoom in",
+            (Action::ZoomOut, _) => "Zoom out",
+            (Action::PanLeft, _) => "Pan left",
+            (Action::PanRight, _) => "Pan right",
+            (Action::PanUp, _) => "Pan up",
+            (Action::PanDown, _) => "Pan down",
+            (Action::MeridianWest, _) => "Central meridian west",
+            (Action::MeridianEast, _) => "Central meridian east",
+            (Action::Projection, _) => "Cycle map projection",
+            (Action::Follow, _) => "Cycle map follow",
+            (Action::FocusStation, _) => "Cycle focus station",
+            (Action::ResetView, _) => "Reset the map",
+            (Action::Search, _) => "Search",
+            (Action::Sort, _) => "Cycle sort",
+            (Action::Reverse, _) => "Reverse sort",
+            (Action::Copy, KeyContext::SatSelect) => "Copy TLE",
+            (Action::Copy, KeyContext::PassDetail) => "Copy table",
+            (Action::Copy, _) => "Copy log",
+            (Action::Fetch, _) => "Fetch TLE (all TLEs on a group header)",
+            (Action::Favourite, _) => "Toggle favourite",
+            (Action::Groups, _) => "Edit groups",
+            (Action::Export, KeyContext::SatSelect) => "Export group TLEs",
+            (Action::Export, _) => "Export",
+            (Action::Paste, _) => "Paste TLE",
+            (Action::Activate, _) => "Toggle station active",
+            (Action::Step, _) => "Cycle table step",
+            (Action::FilterStation, _) => "Filter by station",
+            (Action::FilterElevation, _) => "Cycle minimum elevation",
+            (Action::FilterDuration, _) => "Cycle minimum duration",
+            (Action::FilterTimeOfDay, _) => "Cycle time of day",
+            (Action::ClearFilters, _) => "Clear filters",
+            (Action::Run, _) => "Run screening",
+            (Action::Window, _) => "Cycle window",
+            (Action::Threshold, _) => "Cycle miss distance",
+            (Action::Outcome, _) => "Cycle outcome",
+            (Action::Help, _) => "Key bindings",
+        }
+    }
+}
+
+type DefaultBinding = (KeyContext, Action, &'static [Key]);
+
+/// Bindings used for anything the keymap file does not change
+const DEFAULT_BINDINGS: &[DefaultBinding] = &[
+    (KeyContext::Global, Action::Help, &[Key::Char('?')]),
+    (KeyContext::Base, Action::Close, &[Key::Char('q'), Key::Esc]),
+    (KeyContext::Base, Action::Up, &[Key::Up]),
+    (KeyContext::Base, Action::Down, &[Key::Down]),
+    (KeyContext::Base, Action::Select, &[Key::Enter]),
+    (KeyContext::Base, Action::Satellites, &[Key::Char('s')]),
+    (KeyContext::Base, Action::Stations, &[Key::Char('g')]),
+    (KeyContext::Base, Action::Schedule, &[Key::Char('P')]),
+    (KeyContext::Base, Action::Conjunctions, &[Key::Char('C')]),
+    (KeyContext::Base, Action::Elements, &[Key::Char('E')]),
+    (KeyContext::Base, Action::Alerts, &[Key::Char('A')]),
+    (KeyContext::Base, Action::EventLog, &[Key::Char('L')]),
+    (KeyContext::Base, Action::History, &[Key::Char('H')]),
+    (KeyContext::Base, Action::LinkBudget, &[Key::Char('b')]),
+    (KeyContext::Base, Action::ScrubBack, &[Key::Char('[')]),
+    (KeyContext::Base, Action::ScrubForward, &[Key::Char(']')]),
+    (KeyContext::Base, Action::ScrubBackHour, &[Key::Char('{')]),
+    (
+        KeyContext::Base,
+        Action::ScrubForwardHour,
+        &[Key::Char('}')],
+    ),
+    (KeyContext::Base, Action::Faster, &[Key::Char('+')]),
+    (KeyContext::Base, Action::Slower, &[Key::Char('-')]),
+    (KeyContext::Base, Action::Pause, &[Key::Char('p')]),
+    (KeyContext::Base, Action::NextAos, &[Key::Char('n')]),
+    (KeyContext::Base, Action::RealTime, &[Key::Char('r')]),
+    (KeyContext::Base, Action::Footprint, &[Key::Char('f')]),
+    (
+        KeyContext::Base,
+        Action::FootprintElevation,
+        &[Key::Char('F')],
+    ),
+    (KeyContext::Base, Action::StationRings, &[Key::Char('c')]),
+    (KeyContext::Base, Action::Terminator, &[Key::Char('t')]),
+    (KeyContext::Base, Action::Twilight, &[Key::Char('T')]),
+    (KeyContext::Base, Action::ZoomIn, &[Key::Char('z')]),
+    (KeyContext::Base, Action::ZoomOut, &[Key::Char('Z')]),
+    (KeyContext::Base, Action::PanLeft, &[Key::Char('h')]),
+    (KeyContext::Base, Action::PanRight, &[Key::Char('l')]),
+    (KeyContext::Base, Action::PanUp, &[Key::Char('k')]),
+    (KeyContext::Base, Action::PanDown, &[Key::Char('j')]),
+    (KeyContext::Base, Action::MeridianWest, &[Key::Char('<')]),
+    (KeyContext::Base, Action::MeridianEast, &[Key::Char('>')]),
+    (KeyContext::Base, Action::Projection, &[Key::Char('m')]),
+    (KeyContext::Base, Action::Follow, &[Key::Char('v')]),
+    (KeyContext::Base, Action::FocusStation, &[Key::Char('V')]),
+    (KeyContext::Base, Action::ResetView, &[Key::Char('0')]),
+    (
+        KeyContext::SatSelect,
+        Action::Close,
+        &[Key::Char('q'), Key::Esc],
+    ),
+    (KeyContext::SatSelect, Action::Up, &[Key::Up]),
+    (KeyContext::SatSelect, Action::Down, &[Key::Down]),
+    (KeyContext::SatSelect, Action::Select, &[Key::Enter]),
+    (KeyContext::SatSelect, Action::Search, &[Key::Char('/')]),
+    (KeyContext::SatSelect, Action::Sort, &[Key::Char('o')]),
+    (KeyContext::SatSelect, Action::Fetch, &[Key::Char('f')]),
+    (KeyContext::SatSelect, Action::Copy, &[Key::Char('c')]),
+    (KeyContext::SatSelect, Action::Favourite, &[Key::Char('*')]),
+    (KeyContext::SatSelect, Action::Groups, &[Key::Char('g')]),
+    (KeyContext::SatSelect, Action::Export, &[Key::Char('e')]),
+    (
+        KeyContext::SatAddition,
+        Action::Close,
+        &[Key::Char('q'), Key::Esc],
+    ),
+    (KeyContext::SatAddition, Action::Edit, &[Key::Enter]),
+    (KeyContext::SatAddition, Action::Up, &[Key::Up]),
+    (KeyContext::SatAddition, Action::Down, &[Key::Down]),
+    (KeyContext::SatAddition, Action::Paste, &[Key::Char('v')]),
+    (
+        KeyContext::GSConfig,
+        Action::Close,
+        &[Key::Char('q'), Key::Esc],
+    ),
+    (KeyContext::GSConfig, Action::Up, &[Key::Up]),
+    (KeyContext::GSConfig, Action::Down, &[Key::Down]),
+    (KeyContext::GSConfig, Action::Select, &[Key::Enter]),
+    (KeyContext::GSConfig, Action::Activate, &[Key::Char('a')]),
+    (
+        KeyContext::GSCellSelect,
+        Action::Close,
+        &[Key::Esc, Key::Char('q')],
+    ),
+    (KeyContext::GSCellSelect, Action::Left, &[Key::Left]),
+    (KeyContext::GSCellSelect, Action::Right, &[Key::Right]),
+    (KeyContext::GSCellSelect, Action::Select, &[Key::Enter]),
+    (
+        KeyContext::PassDetail,
+        Action::Close,
+        &[Key::Char('q'), Key::Esc],
+    ),
+    (KeyContext::PassDetail, Action::Up, &[Key::Up]),
+    (KeyContext::PassDetail, Action::Down, &[Key::Down]),
+    (KeyContext::PassDetail, Action::Step, &[Key::Char('s')]),
+    (KeyContext::PassDetail, Action::Export, &[Key::Char('e')]),
+    (KeyContext::PassDetail, Action::Copy, &[Key::Char('c')]),
+    (
+        KeyContext::PassDetail,
+        Action::LinkBudget,
+        &[Key::Char('b')],
+    ),
+    (
+        KeyContext::Schedule,
+        Action::Close,
+        &[Key::Char('q'), Key::Esc],
+    ),
+    (KeyContext::Schedule, Action::Up, &[Key::Up]),
+    (KeyContext::Schedule, Action::Down, &[Key::Down]),
+    (KeyContext::Schedule, Action::Select, &[Key::Enter]),
+    (KeyContext::Schedule, Action::Sort, &[Key::Char('o')]),
+    (KeyContext::Schedule, Action::Reverse, &[Key::Char('r')]),
+    (
+        KeyContext::Schedule,
+        Action::FilterStation,
+        &[Key::Char('f')],
+    ),
+    (
+        KeyContext::Schedule,
+        Action::FilterElevation,
+        &[Key::Char('e')],
+    ),
+    (
+        KeyContext::Schedule,
+        Action::FilterDuration,
+        &[Key::Char('d')],
+    ),
+    (
+        KeyContext::Schedule,
+        Action::FilterTimeOfDay,
+        &[Key::Char('t')],
+    ),
+    (
+        KeyContext::Schedule,
+        Action::ClearFilters,
+        &[Key::Char('x')],
+    ),
+    (
+        KeyContext::Conjunctions,
+        Action::Close,
+        &[Key::Char('q'), Key::Char('C'), Key::Esc],
+    ),
+    (KeyContext::Conjunctions, Action::Up, &[Key::Up]),
+    (KeyContext::Conjunctions, Action::Down, &[Key::Down]),
+    (KeyContext::Conjunctions, Action::Run, &[Key::Enter]),
+    (KeyContext::Conjunctions, Action::Window, &[Key::Char('w')]),
+    (
+        KeyContext::Conjunctions,
+        Action::Threshold,
+        &[Key::Char('d')],
+    ),
+    (KeyContext::Conjunctions, Action::Sort, &[Key::Char('o')]),
+    (KeyContext::Conjunctions, Action::Reverse, &[Key::Char('r')]),
+    (
+        KeyContext::EventLog,
+        Action::Close,
+        &[Key::Char('q'), Key::Char('L'), Key::Esc],
+    ),
+    (KeyContext::EventLog, Action::Up, &[Key::Up]),
+    (KeyContext::EventLog, Action::Down, &[Key::Down]),
+    (
+        KeyContext::History,
+        Action::Close,
+        &[Key::Char('q'), Key::Esc],
+    ),
+    (KeyContext::History, Action::Up, &[Key::Up]),
+    (KeyContext::History, Action::Down, &[Key::Down]),
+    (KeyContext::History, Action::Edit, &[Key::Enter]),
+    (KeyContext::History, Action::Outcome, &[Key::Char('o')]),
+    (KeyContext::History, Action::Export, &[Key::Char('e')]),
+    (KeyContext::History, Action::Copy, &[Key::Char('c')]),
+    (
+        KeyContext::LinkBudget,
+        Action::Close,
+        &[Key::Char('q'), Key::Char('b'), Key::Esc],
+    ),
+    (KeyContext::Help, Action::Close, &[Key::Char('q'), Key::Esc]),
+    (KeyContext::Help, Action::Up, &[Key::Up]),
+    (KeyContext::Help, Action::Down, &[Key::Down]),
+];
+
+/// Contents of `keymap.json`: for each context, the keys for each action that should differ from the defaults
+pub type KeymapFile = HashMap<KeyContext, HashMap<Action, Vec<String>>>;
+
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub context: KeyContext,
+    pub action: Action,
+    pub keys: Vec<Key>,
+}
+
+/// Keys bound to each action, in the order of the defaults
+pub struct Keymap {
+    pub bindings: Vec<Binding>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let mut keymap = Keymap::defaults();
+        match get_keymap() {
+            Ok(x) => keymap.apply(x),
+            Err(x) => warn!("Unable to load keymap, using the default keys: {}", x),
+        }
+        keymap
+    }
+}
+
+impl Keymap {
+    pub fn defaults() -> Keymap {
+        Keymap {
+            bindings: DEFAULT_BINDINGS
+                .iter()
+                .map(|(context, action, keys)| Binding {
+                    context: *context,
+                    action: *action,
+                    keys: keys.to_vec(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Replaces the keys of each action given in the file. Keys taken from another action in the same context are
+    /// unbound there, and actions that do nothing in a context are ignored.
+    pub fn apply(&mut self, overrides: KeymapFile) {
+        for (context, actions) in overrides {
+            for (action, keys) in actions {
+                if !self
+                    .bindings
+                    .iter()
+                    .any(|x| x.context == context && x.action == action)
+                {
+                    warn!("Keymap: {:?} is not available in {:?}", action, context);
+                    continue;
+                }
+                let keys: Vec<Key> = keys
+                    .iter()
+                    .filter_map(|x| {
+                        let key = Key::parse(x);
+                        if key.is_none() {
+                            warn!("Keymap: unknown key {:?} for {:?}", x, action);
+                        }
+                        key
+                    })
+                    .collect();
+                for i in self.bindings.iter_mut().filter(|x| x.context == context) {
+                    if i.action == action {
+                        i.keys = keys.clone();
+                    } else {
+                        i.keys.retain(|x| !keys.contains(x));
+                    }
+                }
+            }
+        }
+    }
+
+    /// The action bound to the key in the context
+    pub fn action(&self, context: KeyContext, key: Key) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|x| x.context == context && x.keys.contains(&key))
+            .map(|x| x.action)
+    }
+
+    /// The action bound to the key in the context, or else its global binding
+    pub fn resolve(&self, context: KeyContext, key: Key) -> Option<(KeyContext, Action)> {
+        match self.action(context, key) {
+            Some(x) => Some((context, x)),
+            None => Some((KeyContext::Global, self.action(KeyContext::Global, key)?)),
+        }
+    }
+
+    /// The keys bound to the action in the context, empty if it is unbound
+    pub fn keys(&self, context: KeyContext, action: Action) -> &[Key] {
+        self.bindings
+            .iter()
+            .find(|x| x.context == context && x.action == action)
+            .map_or(&[], |x| x.keys.as_slice())
+    }
+}
+
+/// Lines of the help popup for the context: a heading, then each bound action with its keys, then the global bindings
+pub fn help_rows(keymap: &Keymap, context: Option<KeyContext>) -> Vec<HelpRow> {
+    let mut rows = vec![];
+    for i in context.into_iter().chain([KeyContext::Global]) {
+        if !rows.is_empty() {
+            rows.push(HelpRow::Blank);
+        }
+        rows.push(HelpRow::Heading(i.name()));
+        rows.extend(
+            keymap
+                .bindings
+                .iter()
+                .filter(|x| x.context == i && !x.keys.is_empty())
+                .map(|x| HelpRow::Binding {
+                    keys: x
+                        .keys
+                        .iter()
+                        .map(|y| y.to_string())
+                        .collect::<Vec<String>>()
+                        .join(" "),
+                    description: x.action.description(i),
+                }),
+        );
+    }
+    rows
+}
+
+pub enum HelpRow {
+    Heading(&'static str),
+    Binding {
+        keys: String,
+        description: &'static str,
+    },
+    Blank,
+}
+
+/// State of the key binding help popup
+pub struct HelpView {
+    pub return_state: AppState,
+    /// Bindings in effect when the help was opened
+    pub context: Option<KeyContext>,
+    pub scroll: usize,
+}
+
+impl Default for HelpView {
+    fn default() -> Self {
+        HelpView {
+            return_state: AppState::Base,
+            context: None,
+            scroll: 0,
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use sky_track::Satellite;
+use tracing::warn;
+
+use crate::app::file_cache::cache::get_link_settings;
+use crate::pass_plan::plan_pass;
+use crate::structs::{AppState, TLPass};
+
+/// Boltzmann's constant in dBW/K/Hz
+const BOLTZMANN: f64 = -228.6;
+
+/// Step (seconds) of the link margin curve
+pub const LINK_CURVE_STEP: i64 = 10;
+
+/// Transmitter of a satellite
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SatelliteRadio {
+    /// dBW
+    pub eirp: f64,
+    /// MHz
+    pub frequency: f64,
+    /// bit/s
+    pub data_rate: f64,
+    /// dB
+    pub required_ebn0: f64,
+}
+
+impl Default for SatelliteRadio {
+    fn default() -> Self {
+        SatelliteRadio {
+            eirp: 0.0,
+            frequency: 437.0,
+            data_rate: 9600.0,
+            required_ebn0: 10.0,
+        }
+    }
+}
+
+/// Receiving chain of a ground station
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StationRadio {
+    /// dBi
+    pub gain: f64,
+    /// K
+    pub noise_temperature: f64,
+    /// dB, covering pointing, polarisation, atmospheric and cable losses
+    pub losses: f64,
+    /// deg, half-power beamwidth of the antenna
+    pub beamwidth: f64,
+}
+
+impl Default for StationRadio {
+    fn default() -> Self {
+        StationRadio {
+            gain: 14.0,
+            noise_temperature: 500.0,
+            losses: 3.0,
+            beamwidth: 20.0,
+        }
+    }
+}
+
+/// Link budget configuration, read from `link.json` in the data directory. Satellites are keyed by NORAD ID and stations by name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LinkSettings {
+    pub satellites: HashMap<String, SatelliteRadio>,
+    pub stations: HashMap<String, StationRadio>,
+    /// Link margin (dB) below which the link is flagged
+    pub margin_threshold: f64,
+    /// Satellite to Sun separation, in station beamwidths, below which a pass is flagged as sun in beam
+    pub sun_beamwidths: f64,
+}
+
+impl Default for LinkSettings {
+    fn default() -> Self {
+        LinkSettings {
+            satellites: HashMap::new(),
+            stations: HashMap::new(),
+            margin_threshold: 3.0,
+            sun_beamwidths: 1.0,
+        }
+    }
+}
+
+impl LinkSettings {
+    pub fn satellite(&self, norad_id: u64) -> SatelliteRadio {
+        self.satellites
+            .get(&norad_id.to_string())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn station(&self, name: &str) -> StationRadio {
+        self.stations.get(name).cloned().unwrap_or_default()
+    }
+
+    /// Whether the Sun comes within the flagging threshold of the satellite during the pass
+    pub fn sun_in_beam(&self, pass: &TLPass) -> bool {
+        pass.sun.separation < self.station(&pass.station.name).beamwidth * self.sun_beamwidths
+    }
+}
+
+/// Downlink budget at one range, all in dB units
+#[derive(Debug, Clone, Copy)]
+pub struct LinkBudget {
+    pub path_loss: f64,
+    pub received_power: f64,
+    pub cn0: f64,
+    pub ebn0: f64,
+    pub margin: f64,
+}
+
+/// Downlink budget for a slant range of `range` km
+pub fn link_budget(satellite: &SatelliteRadio, station: &StationRadio, range: f64) -> LinkBudget {
+    let path_loss = 20.0 * range.max(1e-3).log10() + 20.0 * satellite.frequency.log10() + 32.44;
+    let received_power = satellite.eirp + station.gain - path_loss - station.losses;
+    let cn0 = received_power - BOLTZMANN - 10.0 * station.noise_temperature.log10();
+    let ebn0 = cn0 - 10.0 * satellite.data_rate.log10();
+    LinkBudget {
+        path_loss,
+        received_power,
+        cn0,
+        ebn0,
+        margin: ebn0 - satellite.required_ebn0,
+    }
+}
+
+/// Margin over the pass every `LINK_CURVE_STEP`, as (minutes from AOS, margin in dB, timestamp)
+pub fn margin_curve(
+    satellite: &Satellite,
+    pass: &TLPass,
+    settings: &LinkSettings,
+) -> Vec<(f64, f64, i64)> {
+    let sat_radio = settings.satellite(satellite.get_norad_id());
+    let station_radio = settings.station(&pass.station.name);
+    let aos = pass.pass.get_aos();
+    plan_pass(satellite, pass, LINK_CURVE_STEP)
+        .iter()
+        .map(|x| {
+            (
+                (x.time.timestamp() - aos) as f64 / 60.0,
+                link_budget(&sat_radio, &station_radio, x.range).margin,
+                x.time.timestamp(),
+            )
+        })
+        .collect()
+}
+
+/// Link budget settings and the pass the link budget view shows, with its margin curve worked out when the view opens
+pub struct LinkView {
+    pub settings: LinkSettings,
+    pub pass: Option<TLPass>,
+    pub curve: Vec<(f64, f64, i64)>,
+    pub return_state: AppState,
+}
+
+impl Default for LinkView {
+    fn default() -> Self {
+        let settings = get_link_settings().unwrap_or_else(|x| {
+            warn!("Unable to load link settings: {}", x);
+            LinkSettings::default()
+        });
+        LinkView {
+            settings,
+            pass: None,
+            curve: vec![],
+            return_state: AppState::Base,
+        }
+    }
+}
@@ -1,15 +1,35 @@
 use color_eyre::Result;
 use structs::Model;
+mod alerts;
 mod app;
+mod astro;
+mod clock;
+mod coastlines;
+mod conjunction;
+mod geometry;
+mod ground_track;
+mod history;
+mod hooks;
+mod keymap;
+mod link_budget;
+mod pass_plan;
+mod projection;
+mod sat_groups;
+mod sat_search;
+mod schedule;
+mod theme;
+mod tle;
 mod ui;
 mod utils;
 
 #[cfg(not(target_arch = "wasm32"))]
 mod native {
-    use std::io::Stdout;
+    use std::io::{Stdout, stdout};
 
     use color_eyre::Result;
     use ratatui::Terminal;
+    use ratatui::crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+    use ratatui::crossterm::execute;
     use ratatui::prelude::CrosstermBackend;
     use ratatui::{init, restore};
     use tracing::info;
@@ -19,13 +39,33 @@ mod native {
     use crate::structs::Model;
     use crate::ui::view;
     use crate::utils::native::initialize_logging;
-    fn setup() -> Result<Terminal<CrosstermBackend<Stdout>>> {
-        Ok(init())
+
+    /// Puts the terminal back however `run` exits, including mouse capture which ratatui's own restore leaves on
+    struct TerminalGuard;
+
+    impl Drop for TerminalGuard {
+        fn drop(&mut self) {
+            let _ = execute!(stdout(), DisableMouseCapture);
+            restore();
+        }
+    }
+
+    fn setup() -> Result<(Terminal<CrosstermBackend<Stdout>>, TerminalGuard)> {
+        let terminal = init();
+        let guard = TerminalGuard;
+        // ratatui's panic hook restores the terminal before the panic is printed, so capture has to go there too
+        let hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let _ = execute!(stdout(), DisableMouseCapture);
+            hook(info);
+        }));
+        execute!(stdout(), EnableMouseCapture)?;
+        Ok((terminal, guard))
     }
     pub fn run() -> Result<()> {
         initialize_logging()?;
         color_eyre::install()?;
-        let mut terminal = setup()?;
+        let (mut terminal, _guard) = setup()?;
         let mut model = Model::default();
         info!("Loaded Model");
         while !&model.exit {
@@ -35,7 +75,6 @@ mod native {
                 update(&mut model, msg);
             }
         }
-        restore();
         Ok(())
     }
 }
@@ -50,7 +89,9 @@ mod web {
     use wasm_bindgen::JsCast;
     use wasm_bindgen::prelude::Closure;
 
+    use crate::app::mouse_handle::{handle_mouse_event, handle_wheel_event};
     use crate::app::update;
+    use crate::structs::{AlertMsg, Message};
     use crate::utils::web::initialize_logging;
     use crate::{app::key_handle::handle_event, structs::Model, ui::view};
     use color_eyre::Result;
@@ -77,6 +118,27 @@ mod web {
                 handle_event(&state, key_event, key_tx.clone());
             }
         });
+        terminal.on_mouse_event({
+            let mouse_tx = tx.clone();
+            let model_state = model.clone();
+            move |mouse_event| {
+                let state = model_state.borrow();
+                handle_mouse_event(&state, mouse_event, mouse_tx.clone());
+            }
+        });
+        let wheel_callback = Closure::<dyn Fn(web_sys::WheelEvent)>::new({
+            let wheel_tx = tx.clone();
+            let model_state = model.clone();
+            move |wheel_event| {
+                let state = model_state.borrow();
+                handle_wheel_event(&state, wheel_event, wheel_tx.clone());
+            }
+        });
+        if let Some(document) = web_sys::window().and_then(|x| x.document()) {
+            let _ = document
+                .add_event_listener_with_callback("wheel", wheel_callback.as_ref().unchecked_ref());
+        }
+        wheel_callback.forget();
         terminal.draw_web({
             let model_state = model.clone();
             move |frame| {
@@ -98,6 +160,17 @@ mod web {
                 50,
             );
         callback.forget(); //leaks memory
+        let alert_tx = tx.clone();
+        let alert_callback = Closure::<dyn Fn()>::new(move || {
+            let _ = alert_tx.send(Message::AlertMsg(AlertMsg::Check));
+        });
+        let _ = web_sys::window()
+            .unwrap()
+            .set_interval_with_callback_and_timeout_and_arguments_0(
+                alert_callback.as_ref().unchecked_ref(),
+                1000,
+            );
+        alert_callback.forget();
         Ok(())
     }
 }
@@ -0,0 +1,136 @@
+use chrono::{DateTime, Utc};
+use sky_track::{GroundStation, Pass, Satellite};
+
+use crate::astro::{angular_separation, look_angle, sun_position};
+use crate::structs::TLPass;
+
+/// Seconds between samples in the coarse search for the closest approach to the Sun
+const SUN_SEARCH_STEP: i64 = 10;
+
+/// Pointing from a ground station to the satellite at one instant
+#[derive(Debug, Clone, Copy)]
+pub struct PassSample {
+    pub time: DateTime<Utc>,
+    pub azimuth: f64,
+    pub elevation: f64,
+    pub range: f64,
+    pub range_rate: f64,
+}
+
+/// Look angle and range rate (km/s, positive when receding) from `station` at unix time `time`
+pub fn look_at(satellite: &Satellite, station: &GroundStation, time: i64) -> PassSample {
+    let offset = time - satellite.get_epoch().timestamp();
+    let pointing = satellite.get_look_angle(station, offset);
+    PassSample {
+        time: DateTime::from_timestamp(time, 0).unwrap_or_default(),
+        azimuth: pointing.azimuth,
+        elevation: pointing.elevation,
+        range: pointing.range,
+        range_rate: range_rate(satellite, station, offset),
+    }
+}
+
+/// Rate of change of range (km/s) at `offset` seconds after the TLE epoch, by central difference
+pub fn range_rate(satellite: &Satellite, station: &GroundStation, offset: i64) -> f64 {
+    let before = satellite.get_look_angle(station, offset - 1).range;
+    let after = satellite.get_look_angle(station, offset + 1).range;
+    (after - before) / 2.0
+}
+
+/// Closest approach of the satellite to the Sun during a pass, as seen from the station
+#[derive(Debug, Clone, Copy)]
+pub struct SunApproach {
+    pub time: DateTime<Utc>,
+    /// deg
+    pub separation: f64,
+}
+
+/// Angle (deg) between the satellite and the Sun from `station` at unix time `time`
+fn sun_separation(satellite: &Satellite, station: &GroundStation, time: i64) -> f64 {
+    let pointing = satellite.get_look_angle(station, time - satellite.get_epoch().timestamp());
+    let date = DateTime::from_timestamp(time, 0).unwrap_or_default();
+    let sun = look_angle(
+        &sun_position(&date),
+        &date,
+        station.lat,
+        station.long,
+        station.alt / 1000.0,
+    );
+    angular_separation(
+        (pointing.azimuth, pointing.elevation),
+        (sun.azimuth, sun.elevation),
+    )
+}
+
+/// Finds the closest approach to the Sun over the pass, coarsely then to the second around the best sample
+pub fn sun_approach(satellite: &Satellite, station: &GroundStation, pass: &Pass) -> SunApproach {
+    let (aos, los) = (pass.get_aos(), pass.get_los());
+    let closest = |times: Vec<i64>| {
+        times
+            .into_iter()
+            .map(|x| (x, sun_separation(satellite, station, x)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap_or((aos, 180.0))
+    };
+    let mut coarse: Vec<i64> = (aos..los).step_by(SUN_SEARCH_STEP as usize).collect();
+    coarse.push(los);
+    let (time, _) = closest(coarse);
+    let (time, separation) =
+        closest(((time - SUN_SEARCH_STEP).max(aos)..=(time + SUN_SEARCH_STEP).min(los)).collect());
+    SunApproach {
+        time: DateTime::from_timestamp(time, 0).unwrap_or_default(),
+        separation,
+    }
+}
+
+/// Samples the pass from AOS to LOS every `step` seconds, always including LOS
+pub fn plan_pass(satellite: &Satellite, pass: &TLPass, step: i64) -> Vec<PassSample> {
+    let (aos, los) = (pass.pass.get_aos(), pass.pass.get_los());
+    let mut plan: Vec<PassSample> = (aos..los)
+        .step_by(step.max(1) as usize)
+        .map(|x| look_at(satellite, &pass.station, x))
+        .collect();
+    plan.push(look_at(satellite, &pass.station, los));
+    plan
+}
+
+/// The pass plan as CSV, with a commented header describing the pass
+pub fn plan_to_csv(satellite: &Satellite, pass: &TLPass, plan: &[PassSample]) -> String {
+    let mut output = format!(
+        "# {} ({}) over {}, AOS {}, LOS {}, max elevation {:.1} deg\n",
+        satellite.get_name(),
+        satellite.get_norad_id(),
+        pass.station.name,
+        pass.pass.get_aos_datetime().format("%Y-%m-%dT%H:%M:%SZ"),
+        pass.pass.get_los_datetime().format("%Y-%m-%dT%H:%M:%SZ"),
+        pass.pass.get_max_elevation()
+    );
+    output.push_str("time_utc,azimuth_deg,elevation_deg,range_km,range_rate_km_s\n");
+    for i in plan {
+        output.push_str(&format!(
+            "{},{:.2},{:.2},{:.2},{:.3}\n",
+            i.time.format("%Y-%m-%dT%H:%M:%SZ"),
+            i.azimuth,
+            i.elevation,
+            i.range,
+            i.range_rate
+        ));
+    }
+    output
+}
+
+/// File name for an exported pass plan, safe to use on any filesystem
+pub fn plan_file_name(satellite: &Satellite, pass: &TLPass) -> String {
+    let station: String = pass
+        .station
+        .name
+        .chars()
+        .map(|x| if x.is_ascii_alphanumeric() { x } else { '_' })
+        .collect();
+    format!(
+        "pass_{}_{}_{}.csv",
+        satellite.get_norad_id(),
+        station,
+        pass.pass.get_aos_datetime().format("%Y%m%dT%H%M%SZ")
+    )
+}
@@ -0,0 +1,221 @@
+use ratatui::layout::{Position, Rect};
+
+use crate::astro::sub_point;
+use crate::coastlines::COASTLINES;
+use crate::geometry::{
+    PathSegment, destination_point, distance_bearing, split_antimeridian, unwrap_longitudes,
+    wrap_longitude,
+};
+use crate::structs::{MapFollow, Model, Projection};
+
+/// Largest jump (deg) between projected points before a path is broken, for projections without a fixed seam
+const MAX_PROJECTED_STEP: f64 = 90.0;
+
+/// Parameters that decide where a point lands on the map, independent of zoom and pan
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProjectionKey {
+    projection: Projection,
+    central_meridian: f64,
+    origin: (f64, f64),
+}
+
+/// Maps (lat, long) onto the map canvas for the current projection, zoom and pan
+pub struct MapProjection {
+    key: ProjectionKey,
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+}
+
+impl MapProjection {
+    pub fn new(model: &Model, area: Rect) -> MapProjection {
+        let settings = &model.map;
+        let focus_station = model
+            .station_config
+            .station_list
+            .iter()
+            .filter(|x| x.active)
+            .nth(settings.focus_station)
+            .map(|x| (x.station.lat, x.station.long));
+        let origin = match settings.projection {
+            Projection::Equirectangular => (0.0, settings.central_meridian),
+            Projection::AzimuthalEquidistant => {
+                focus_station.unwrap_or((0.0, settings.central_meridian))
+            }
+        };
+        let mut projection = MapProjection {
+            key: ProjectionKey {
+                projection: settings.projection,
+                central_meridian: settings.central_meridian,
+                origin,
+            },
+            x_bounds: [-180.0, 180.0],
+            y_bounds: [-90.0, 90.0],
+        };
+        let base = match settings.follow {
+            MapFollow::Free => None,
+            MapFollow::Station => focus_station,
+            MapFollow::Satellite => match model.target {
+                Some(body) => {
+                    let now = model.clock.now();
+                    Some(sub_point(&body.position(&now), &now))
+                }
+                None => model.current_satellite.as_ref().map(|x| {
+                    let sub_point = x.satellite.get_sub_point(
+                        model.clock.now().timestamp() - x.satellite.get_epoch().timestamp(),
+                    );
+                    (sub_point.lat, sub_point.long)
+                }),
+            },
+        }
+        .map(|(lat, long)| projection.project(lat, long))
+        .unwrap_or((0.0, 0.0));
+        let center = (base.0 + settings.pan.0, base.1 + settings.pan.1);
+        let (half_width, half_height) = match settings.projection {
+            Projection::Equirectangular => (180.0 / settings.zoom, 90.0 / settings.zoom),
+            Projection::AzimuthalEquidistant => {
+                let half_height = 180.0 / settings.zoom;
+                let aspect = if area.height == 0 {
+                    1.0
+                } else {
+                    f64::from(area.width) / (2.0 * f64::from(area.height))
+                };
+                (half_height * aspect, half_height)
+            }
+        };
+        projection.x_bounds = [center.0 - half_width, center.0 + half_width];
+        projection.y_bounds = [center.1 - half_height, center.1 + half_height];
+        projection
+    }
+
+    pub fn key(&self) -> ProjectionKey {
+        self.key
+    }
+
+    pub fn x_bounds(&self) -> [f64; 2] {
+        self.x_bounds
+    }
+
+    pub fn y_bounds(&self) -> [f64; 2] {
+        self.y_bounds
+    }
+
+    pub fn project(&self, lat: f64, long: f64) -> (f64, f64) {
+        match self.key.projection {
+            Projection::Equirectangular => (wrap_longitude(long - self.key.central_meridian), lat),
+            Projection::AzimuthalEquidistant => {
+                let (distance, bearing) =
+                    distance_bearing(self.key.origin.0, self.key.origin.1, lat, long);
+                let bearing = bearing.to_radians();
+                (distance * bearing.sin(), distance * bearing.cos())
+            }
+        }
+    }
+
+    /// The (lat, long) at a point on the canvas, or nothing if it is off the edge of the projection
+    pub fn unproject(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        match self.key.projection {
+            Projection::Equirectangular => {
+                (y.abs() <= 90.0).then(|| (y, wrap_longitude(x + self.key.central_meridian)))
+            }
+            Projection::AzimuthalEquidistant => {
+                let distance = x.hypot(y);
+                if distance > 180.0 {
+                    return None;
+                }
+                let bearing = x.atan2(y).to_degrees();
+                let (long, lat) =
+                    destination_point(self.key.origin.0, self.key.origin.1, bearing, distance);
+                Some((lat, long))
+            }
+        }
+    }
+
+    /// The (lat, long) under the centre of a terminal cell of the map drawn in `area`
+    pub fn cell_position(&self, area: Rect, column: u16, row: u16) -> Option<(f64, f64)> {
+        if !area.contains(Position::new(column, row)) {
+            return None;
+        }
+        let x = self.x_bounds[0]
+            + (f64::from(column - area.x) + 0.5) / f64::from(area.width)
+                * (self.x_bounds[1] - self.x_bounds[0]);
+        let y = self.y_bounds[1]
+            - (f64::from(row - area.y) + 0.5) / f64::from(area.height)
+                * (self.y_bounds[1] - self.y_bounds[0]);
+        self.unproject(x, y)
+    }
+
+    /// Projects a path of (long, lat) points, breaking it wherever it crosses the edge of the projection
+    pub fn project_path(&self, points: &[(f64, f64)]) -> Vec<Vec<(f64, f64)>> {
+        self.project_timed_path(points, &vec![0.0; points.len()])
+            .into_iter()
+            .map(|x| x.points)
+            .collect()
+    }
+
+    /// As [`MapProjection::project_path`], keeping the time of each point
+    pub fn project_timed_path(&self, points: &[(f64, f64)], times: &[f64]) -> Vec<PathSegment> {
+        match self.key.projection {
+            Projection::Equirectangular => {
+                let shifted: Vec<(f64, f64)> = points
+                    .iter()
+                    .map(|(long, lat)| (long - self.key.central_meridian, *lat))
+                    .collect();
+                split_antimeridian(&unwrap_longitudes(&shifted), times)
+            }
+            Projection::AzimuthalEquidistant => {
+                let mut segments: Vec<PathSegment> = vec![];
+                let mut current = PathSegment::default();
+                for ((long, lat), time) in points.iter().zip(times) {
+                    let point = self.project(*lat, *long);
+                    if current.points.last().is_some_and(|prev| {
+                        (point.0 - prev.0).hypot(point.1 - prev.1) > MAX_PROJECTED_STEP
+                    }) {
+                        segments.push(current);
+                        current = PathSegment::default();
+                    }
+                    current.points.push(point);
+                    current.times.push(*time);
+                }
+                segments.push(current);
+                segments.retain(|x| x.points.len() > 1);
+                segments
+            }
+        }
+    }
+}
+
+/// Coastline points projected for the map, only reprojected when the projection changes
+pub struct CoastlineCache {
+    projection: ProjectionKey,
+    points: Vec<(f64, f64)>,
+}
+
+impl Default for CoastlineCache {
+    /// The default whole world view leaves the points where they are
+    fn default() -> Self {
+        CoastlineCache {
+            projection: ProjectionKey {
+                projection: Projection::Equirectangular,
+                central_meridian: 0.0,
+                origin: (0.0, 0.0),
+            },
+            points: COASTLINES.to_vec(),
+        }
+    }
+}
+
+impl CoastlineCache {
+    pub fn update(&mut self, projection: &MapProjection) {
+        if self.projection != projection.key() {
+            self.points = COASTLINES
+                .iter()
+                .map(|(long, lat)| projection.project(*lat, *long))
+                .collect();
+            self.projection = projection.key();
+        }
+    }
+
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+}
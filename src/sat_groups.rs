@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc::{Receiver, Sender, channel};
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::app::file_cache::cache::get_sat_tags;
+use crate::structs::TLSatellite;
+
+/// Favourites and user-defined groups, kept by NORAD ID in `groups.json` so the celestrak cache stays as fetched
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SatTags {
+    pub favourites: Vec<u64>,
+    pub groups: BTreeMap<String, Vec<u64>>,
+}
+
+/// A header in the satellite list
+#[derive(Debug, Clone, PartialEq)]
+pub enum SatGroup {
+    Favourites,
+    Named(String),
+    Ungrouped,
+}
+
+impl SatGroup {
+    pub fn name(&self) -> &str {
+        match self {
+            SatGroup::Favourites => "Favourites",
+            SatGroup::Named(x) => x,
+            SatGroup::Ungrouped => "Ungrouped",
+        }
+    }
+}
+
+impl SatTags {
+    pub fn is_empty(&self) -> bool {
+        self.favourites.is_empty() && self.groups.is_empty()
+    }
+
+    pub fn is_favourite(&self, norad_id: u64) -> bool {
+        self.favourites.contains(&norad_id)
+    }
+
+    pub fn toggle_favourite(&mut self, norad_id: u64) {
+        if self.is_favourite(norad_id) {
+            self.favourites.retain(|x| *x != norad_id);
+        } else {
+            self.favourites.push(norad_id);
+        }
+    }
+
+    /// Names of the groups the satellite is in
+    pub fn groups_of(&self, norad_id: u64) -> Vec<&str> {
+        self.groups
+            .iter()
+            .filter(|x| x.1.contains(&norad_id))
+            .map(|x| x.0.as_str())
+            .collect()
+    }
+
+    /// Puts the satellite in exactly the given groups, dropping any group left empty
+    pub fn set_groups(&mut self, norad_id: u64, groups: &[String]) {
+        for members in self.groups.values_mut() {
+            members.retain(|x| *x != norad_id);
+        }
+        for i in groups {
+            self.groups.entry(i.clone()).or_default().push(norad_id);
+        }
+        self.groups.retain(|_, x| !x.is_empty());
+    }
+
+    /// Headers in display order: favourites, the named groups alphabetically, then everything not in a named group
+    pub fn headers(&self) -> Vec<SatGroup> {
+        let mut headers = vec![];
+        if !self.favourites.is_empty() {
+            headers.push(SatGroup::Favourites);
+        }
+        headers.extend(self.groups.keys().map(|x| SatGroup::Named(x.clone())));
+        headers.push(SatGroup::Ungrouped);
+        headers
+    }
+
+    pub fn contains(&self, group: &SatGroup, norad_id: u64) -> bool {
+        match group {
+            SatGroup::Favourites => self.is_favourite(norad_id),
+            SatGroup::Named(x) => self.groups.get(x).is_some_and(|y| y.contains(&norad_id)),
+            SatGroup::Ungrouped => self.groups_of(norad_id).is_empty(),
+        }
+    }
+
+    /// Indices into `satellites` of the group's members
+    pub fn members(&self, group: &SatGroup, satellites: &[TLSatellite]) -> Vec<usize> {
+        (0..satellites.len())
+            .filter(|x| self.contains(group, satellites[*x].satellite.get_norad_id()))
+            .collect()
+    }
+}
+
+/// TLEs fetched for a group's members, in the background natively
+#[derive(Debug, Clone)]
+pub struct GroupFetch {
+    pub group: String,
+    /// NORAD ID and TLE of each member that returned a valid TLE
+    pub tles: Vec<(u64, String)>,
+    pub requested: usize,
+}
+
+/// Group state of the satellite list, including the comma separated group editor. Natively a group's TLEs are
+/// fetched on their own thread and reported back over the channel.
+pub struct SatGroups {
+    pub tags: SatTags,
+    pub editing: bool,
+    pub buffer: String,
+    pub fetching: bool,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub sender: Sender<GroupFetch>,
+    #[cfg(not(target_arch = "wasm32"))]
+    receiver: Receiver<GroupFetch>,
+}
+
+impl Default for SatGroups {
+    fn default() -> Self {
+        let tags = get_sat_tags().unwrap_or_else(|x| {
+            warn!("Unable to load satellite groups: {}", x);
+            SatTags::default()
+        });
+        #[cfg(not(target_arch = "wasm32"))]
+        let (sender, receiver) = channel();
+        SatGroups {
+            tags,
+            editing: false,
+            buffer: String::new(),
+            fetching: false,
+            #[cfg(not(target_arch = "wasm32"))]
+            sender,
+            #[cfg(not(target_arch = "wasm32"))]
+            receiver,
+        }
+    }
+}
+
+impl SatGroups {
+    /// The group's TLEs being fetched in the background, once they have all come back
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn finished(&self) -> Option<GroupFetch> {
+        self.receiver.try_recv().ok()
+    }
+}
+
+/// The group's TLEs in 3-line form, one after another
+pub fn group_tles(satellites: &[&TLSatellite]) -> String {
+    let mut output = String::new();
+    for i in satellites {
+        let tle = i.satellite.get_tle().trim();
+        if tle.starts_with("1 ") {
+            output.push_str(&i.satellite.get_name());
+            output.push('\n');
+        }
+        output.push_str(tle);
+        output.push('\n');
+    }
+    output
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use crate::structs::TLSatellite;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SatSort {
+    #[default]
+    Added,
+    Name,
+    Norad,
+    TleAge,
+    NextPass,
+}
+
+impl SatSort {
+    pub fn next(self) -> SatSort {
+        match self {
+            SatSort::Added => SatSort::Name,
+            SatSort::Name => SatSort::Norad,
+            SatSort::Norad => SatSort::TleAge,
+            SatSort::TleAge => SatSort::NextPass,
+            SatSort::NextPass => SatSort::Added,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SatSort::Added => "Added",
+            SatSort::Name => "Name",
+            SatSort::Norad => "NORAD ID",
+            SatSort::TleAge => "TLE Age",
+            SatSort::NextPass => "Next Pass",
+        }
+    }
+}
+
+/// Field of a satellite a search matched on
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatchField {
+    Name,
+    Norad,
+    Cospar,
+    Owner,
+}
+
+/// Where the search matched a satellite, with the matched character positions in that field
+#[derive(Debug, Clone)]
+pub struct SatMatch {
+    pub field: MatchField,
+    pub text: String,
+    pub positions: Vec<usize>,
+}
+
+/// Search and sort state of the satellite list
+#[derive(Default)]
+pub struct SatSearch {
+    pub query: String,
+    pub editing: bool,
+    pub sort: SatSort,
+    /// Next AOS (unix time) by NORAD ID over the active stations, filled in when sorting by next pass
+    pub next_passes: HashMap<u64, i64>,
+}
+
+impl SatSearch {
+    /// Best match of the query against the satellite's name, NORAD ID, COSPAR ID and owner, or nothing if it is filtered out
+    pub fn matches(&self, satellite: &TLSatellite) -> Option<SatMatch> {
+        let fields = [
+            (MatchField::Name, satellite.satellite.get_name()),
+            (
+                MatchField::Norad,
+                satellite.satellite.get_norad_id().to_string(),
+            ),
+            (MatchField::Cospar, satellite.metadata.object_id.clone()),
+            (MatchField::Owner, satellite.metadata.owner.clone()),
+        ];
+        fields
+            .into_iter()
+            .filter_map(|(field, text)| {
+                fuzzy_match(&self.query, &text).map(|(score, positions)| {
+                    (
+                        score,
+                        SatMatch {
+                            field,
+                            text,
+                            positions,
+                        },
+                    )
+                })
+            })
+            .max_by_key(|x| x.0)
+            .map(|x| x.1)
+    }
+
+    /// Indices into `satellites` of those matching the query, in the selected order
+    pub fn visible(&self, satellites: &[TLSatellite]) -> Vec<usize> {
+        let mut output: Vec<usize> = (0..satellites.len())
+            .filter(|x| self.matches(&satellites[*x]).is_some())
+            .collect();
+        match self.sort {
+            SatSort::Added => {}
+            SatSort::Name => output.sort_by_key(|x| satellites[*x].satellite.get_name()),
+            SatSort::Norad => output.sort_by_key(|x| satellites[*x].satellite.get_norad_id()),
+            SatSort::TleAge => {
+                output.sort_by_key(|x| std::cmp::Reverse(satellites[*x].satellite.get_epoch()))
+            }
+            SatSort::NextPass => output.sort_by_key(|x| {
+                self.next_passes
+                    .get(&satellites[*x].satellite.get_norad_id())
+                    .copied()
+                    .unwrap_or(i64::MAX)
+            }),
+        }
+        output
+    }
+}
+
+/// Case-insensitive fuzzy match of `query` in `text`, preferring a contiguous substring and otherwise
+/// taking the characters in order. Returns a score (higher is better) and the matched character positions.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<(i64, Vec<usize>)> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|x| !x.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if query.is_empty() {
+        return Some((0, vec![]));
+    }
+    let chars: Vec<char> = text.chars().collect();
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|x| x.to_lowercase().next().unwrap_or(*x))
+        .collect();
+    if let Some(start) = lower
+        .windows(query.len())
+        .position(|x| x == query.as_slice())
+    {
+        let bonus = if start == 0 { 20 } else { 10 };
+        return Some((
+            100 + bonus - start as i64,
+            (start..start + query.len()).collect(),
+        ));
+    }
+    let mut positions = vec![];
+    let mut score = 0;
+    let mut next = 0;
+    for i in query {
+        let found = (next..lower.len()).find(|x| lower[*x] == i)?;
+        if found > 0 && positions.last() == Some(&(found - 1)) {
+            score += 5;
+        } else if found == 0 || !chars[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        score -= (found - next).min(5) as i64;
+        positions.push(found);
+        next = found + 1;
+    }
+    Some((score, positions))
+}
@@ -0,0 +1,125 @@
+use chrono::{Local, Timelike};
+use ratatui::widgets::TableState;
+
+use crate::structs::TLPass;
+
+/// Minimum max-elevation filters (deg) cycled through in the schedule
+pub const MIN_ELEVATIONS: [f64; 6] = [0.0, 5.0, 10.0, 20.0, 30.0, 45.0];
+
+/// Minimum pass duration filters (seconds) cycled through in the schedule
+pub const MIN_DURATIONS: [i64; 5] = [0, 120, 300, 420, 600];
+
+/// Local time of day windows for AOS as (start hour, end hour), wrapping past midnight when start > end
+pub const TIME_OF_DAY_WINDOWS: [(u32, u32); 7] = [
+    (0, 24),
+    (6, 18),
+    (18, 6),
+    (0, 6),
+    (6, 12),
+    (12, 18),
+    (18, 24),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScheduleSort {
+    Station,
+    Aos,
+    Los,
+    Duration,
+    MaxElevation,
+}
+
+impl ScheduleSort {
+    pub fn next(self) -> ScheduleSort {
+        match self {
+            ScheduleSort::Station => ScheduleSort::Aos,
+            ScheduleSort::Aos => ScheduleSort::Los,
+            ScheduleSort::Los => ScheduleSort::Duration,
+            ScheduleSort::Duration => ScheduleSort::MaxElevation,
+            ScheduleSort::MaxElevation => ScheduleSort::Station,
+        }
+    }
+}
+
+/// Which of the predicted passes are shown in the schedule
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleFilter {
+    pub station: Option<String>,
+    pub min_elevation: usize,
+    pub min_duration: usize,
+    pub time_of_day: usize,
+}
+
+impl ScheduleFilter {
+    pub fn min_elevation(&self) -> f64 {
+        MIN_ELEVATIONS[self.min_elevation % MIN_ELEVATIONS.len()]
+    }
+
+    pub fn min_duration(&self) -> i64 {
+        MIN_DURATIONS[self.min_duration % MIN_DURATIONS.len()]
+    }
+
+    pub fn time_of_day(&self) -> (u32, u32) {
+        TIME_OF_DAY_WINDOWS[self.time_of_day % TIME_OF_DAY_WINDOWS.len()]
+    }
+
+    pub fn matches(&self, pass: &TLPass) -> bool {
+        let (start, end) = self.time_of_day();
+        let hour = pass.pass.get_aos_datetime().with_timezone(&Local).hour();
+        let in_window = if start <= end {
+            hour >= start && hour < end
+        } else {
+            hour >= start || hour < end
+        };
+        self.station
+            .as_ref()
+            .is_none_or(|x| *x == pass.station.name)
+            && pass.pass.get_max_elevation() >= self.min_elevation()
+            && pass.pass.get_duration_sec() >= self.min_duration()
+            && in_window
+    }
+}
+
+/// State of the pass schedule screen
+pub struct Schedule {
+    pub table_state: TableState,
+    pub sort: ScheduleSort,
+    pub descending: bool,
+    pub filter: ScheduleFilter,
+}
+
+impl Default for Schedule {
+    fn default() -> Self {
+        Schedule {
+            table_state: TableState::default().with_selected(Some(0)),
+            sort: ScheduleSort::Aos,
+            descending: false,
+            filter: ScheduleFilter::default(),
+        }
+    }
+}
+
+impl Schedule {
+    /// The passes that match the filter, in the selected order
+    pub fn passes<'a>(&self, passes: &'a [TLPass]) -> Vec<&'a TLPass> {
+        let mut output: Vec<&TLPass> = passes.iter().filter(|x| self.filter.matches(x)).collect();
+        output.sort_by(|a, b| {
+            let order = match self.sort {
+                ScheduleSort::Station => a.station.name.cmp(&b.station.name),
+                ScheduleSort::Aos => a.pass.get_aos().cmp(&b.pass.get_aos()),
+                ScheduleSort::Los => a.pass.get_los().cmp(&b.pass.get_los()),
+                ScheduleSort::Duration => a.pass.get_duration_sec().cmp(&b.pass.get_duration_sec()),
+                ScheduleSort::MaxElevation => a
+                    .pass
+                    .get_max_elevation()
+                    .total_cmp(&b.pass.get_max_elevation()),
+            };
+            if self.descending {
+                order.reverse()
+            } else {
+                order
+            }
+        });
+        output
+    }
+}
@@ -17,12 +17,15 @@ use futures::executor;
 #[cfg(target_arch = "wasm32")]
 use ratzilla::web_sys;
 
+use ratatui::layout::Rect;
 use ratatui::widgets::ListState;
 
 use serde_json::from_str;
 
 use color_eyre::Result;
 
+use std::cell::RefCell;
+
 use ratatui::widgets::TableState;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -35,8 +38,26 @@ use tracing::debug;
 use tracing::info;
 use tracing::warn;
 
+use crate::alerts::{AlertState, Alerts};
 use crate::app::file_cache::cache::get_gs_cache;
 use crate::app::file_cache::cache::get_sat_cache;
+use crate::astro::{CELESTIAL_BODIES, CelestialBody};
+use crate::clock::Clock;
+use crate::conjunction::{Conjunctions, Screening};
+use crate::ground_track::TrackCache;
+use crate::history::History;
+use crate::hooks::Hooks;
+use crate::keymap::{HelpView, Keymap};
+use crate::link_budget::LinkView;
+use crate::pass_plan::{PassSample, SunApproach};
+use crate::projection::CoastlineCache;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::sat_groups::GroupFetch;
+use crate::sat_groups::{SatGroup, SatGroups};
+use crate::sat_search::{SatSearch, fuzzy_match};
+use crate::schedule::Schedule;
+use crate::theme::Theme;
+use crate::tle::TleError;
 
 #[derive(Clone)]
 pub enum ListMovement {
@@ -45,6 +66,8 @@ pub enum ListMovement {
     Left,
     Right,
     Select,
+    /// A row clicked with the mouse: selects it, or acts as `Select` if it is already selected
+    Click(usize),
 }
 
 #[derive(Clone)]
@@ -53,6 +76,21 @@ pub enum SatList {
     CopyTLE,
     FetchTLE,
     AddSatellite,
+    StartSearch,
+    StopSearch,
+    ClearSearch,
+    SearchTyped(KeyCode),
+    SearchBackspace,
+    CycleSort,
+    ToggleFavourite,
+    EditGroups,
+    GroupTyped(KeyCode),
+    GroupBackspace,
+    SaveGroups,
+    CancelGroups,
+    ExportGroup,
+    #[cfg(not(target_arch = "wasm32"))]
+    GroupFetched(GroupFetch),
 }
 
 #[derive(Clone)]
@@ -75,6 +113,8 @@ pub struct AddSatState {
     pub selected: AddSatSel,
     pub text: String,
     pub editing: bool,
+    /// Problems found in the entered TLE
+    pub errors: Vec<TleError>,
     #[cfg(target_arch = "wasm32")]
     waiting: bool,
 }
@@ -85,12 +125,98 @@ impl Default for AddSatState {
             selected: AddSatSel::NoradID,
             text: String::default(),
             editing: false,
+            errors: vec![],
             #[cfg(target_arch = "wasm32")]
             waiting: false,
         }
     }
 }
 
+#[derive(Clone)]
+pub enum ClockMsg {
+    Scrub(i64),
+    Faster,
+    Slower,
+    TogglePause,
+    JumpNextAOS,
+    RealTime,
+}
+
+#[derive(Clone)]
+pub enum MapMsg {
+    ToggleFootprint,
+    CycleFootprintElevation,
+    ToggleStationRings,
+    ToggleTerminator,
+    ToggleTwilight,
+    ZoomIn,
+    ZoomOut,
+    Pan(f64, f64),
+    ShiftMeridian(f64),
+    CycleProjection,
+    CycleFollow,
+    CycleFocusStation,
+    ResetView,
+    /// Marks the (lat, long) clicked on the map
+    Click(f64, f64),
+}
+
+#[derive(Clone)]
+pub enum PassDetailMsg {
+    Open(TLPass),
+    Close,
+    ListMovement(ListMovement),
+    CycleStep,
+    Export,
+    CopyTable,
+}
+
+#[derive(Clone)]
+pub enum ScheduleMsg {
+    ListMovement(ListMovement),
+    CycleSort,
+    ReverseSort,
+    CycleStation,
+    CycleMinElevation,
+    CycleMinDuration,
+    CycleTimeOfDay,
+    ClearFilters,
+}
+
+#[derive(Clone)]
+pub enum AlertMsg {
+    Check,
+    Toggle,
+}
+
+#[derive(Clone)]
+pub enum HistoryMsg {
+    ListMovement(ListMovement),
+    CycleOutcome,
+    ToggleEditing,
+    LetterTyped(KeyCode),
+    Backspace,
+    Export,
+    CopyLog,
+}
+
+#[derive(Clone)]
+pub enum LinkMsg {
+    Open,
+    Close,
+}
+
+#[derive(Clone)]
+pub enum ConjunctionMsg {
+    ListMovement(ListMovement),
+    Run,
+    CycleWindow,
+    CycleThreshold,
+    CycleSort,
+    ReverseSort,
+    Results(Screening),
+}
+
 #[derive(Clone)]
 pub enum Message {
     Close,
@@ -99,6 +225,23 @@ pub enum Message {
     AddSatMessage(AddSatMsg),
     ToggleGSConfig,
     GSConfigMsg(GSConfigMsg),
+    ClockMsg(ClockMsg),
+    MapMsg(MapMsg),
+    PassListMsg(ListMovement),
+    PassDetailMsg(PassDetailMsg),
+    ToggleSchedule,
+    ScheduleMsg(ScheduleMsg),
+    AlertMsg(AlertMsg),
+    ToggleEventLog,
+    EventLogMsg(ListMovement),
+    ToggleHistory,
+    HistoryMsg(HistoryMsg),
+    LinkMsg(LinkMsg),
+    ToggleConjunctions,
+    ToggleElements,
+    ToggleHelp,
+    HelpMsg(ListMovement),
+    ConjunctionMsg(ConjunctionMsg),
     PropagatePasses,
     #[cfg(target_arch = "wasm32")]
     UpdatePass,
@@ -206,6 +349,77 @@ pub struct SatSelection {
     pub clipboard: TLClipboard,
     pub current_message: CurrentMsg,
     pub add_sat: AddSatState,
+    pub search: SatSearch,
+    pub groups: SatGroups,
+}
+
+/// A row of the satellite list
+#[derive(Debug, Clone, PartialEq)]
+pub enum SatEntry {
+    Group(SatGroup),
+    Satellite(usize),
+    Body(CelestialBody),
+    AddSatellite,
+}
+
+impl SatSelection {
+    /// Rows of the satellite list in display order: the matching satellites (under their group headers once any
+    /// groups or favourites exist), the matching bodies, then the add entry
+    pub fn entries(&self) -> Vec<SatEntry> {
+        let visible = self.search.visible(&self.satellite_list);
+        let tags = &self.groups.tags;
+        let mut entries: Vec<SatEntry> = vec![];
+        if tags.is_empty() {
+            entries.extend(visible.into_iter().map(SatEntry::Satellite));
+        } else {
+            for group in tags.headers() {
+                let members: Vec<usize> = visible
+                    .iter()
+                    .copied()
+                    .filter(|x| {
+                        tags.contains(&group, self.satellite_list[*x].satellite.get_norad_id())
+                    })
+                    .collect();
+                if members.is_empty() {
+                    continue;
+                }
+                entries.push(SatEntry::Group(group));
+                entries.extend(members.into_iter().map(SatEntry::Satellite));
+            }
+        }
+        entries.extend(
+            CELESTIAL_BODIES
+                .iter()
+                .filter(|x| fuzzy_match(&self.search.query, x.name()).is_some())
+                .map(|x| SatEntry::Body(*x)),
+        );
+        entries.push(SatEntry::AddSatellite);
+        entries
+    }
+
+    /// The selected row of the satellite list
+    pub fn selected(&self) -> Option<SatEntry> {
+        self.entries().get(self.list_state.selected()?).cloned()
+    }
+
+    /// The satellite in the selected row, as (index into `satellite_list`, satellite)
+    pub fn selected_satellite(&self) -> Option<(usize, &TLSatellite)> {
+        match self.selected()? {
+            SatEntry::Satellite(x) => Some((x, &self.satellite_list[x])),
+            _ => None,
+        }
+    }
+
+    /// The group header in the selected row and the indices of its members
+    pub fn selected_group(&self) -> Option<(SatGroup, Vec<usize>)> {
+        match self.selected()? {
+            SatEntry::Group(x) => {
+                let members = self.groups.tags.members(&x, &self.satellite_list);
+                Some((x, members))
+            }
+            _ => None,
+        }
+    }
 }
 
 impl Default for SatSelection {
@@ -234,6 +448,8 @@ impl Default for SatSelection {
             clipboard: TLClipboard::new(),
             current_message,
             add_sat: AddSatState::default(),
+            search: SatSearch::default(),
+            groups: SatGroups::default(),
         }
     }
 }
@@ -244,17 +460,143 @@ pub enum AppState {
     SatSelect,
     SatAddition,
     GSConfig,
+    PassDetail,
+    Schedule,
+    EventLog,
+    History,
+    LinkBudget,
+    Conjunctions,
+    Help,
     #[cfg(target_arch = "wasm32")]
     SatWaitingFetch,
 }
 
+/// Sampling steps (seconds) the pass plan table can be shown at
+pub const PLAN_STEPS: [i64; 5] = [1, 5, 10, 30, 60];
+
+pub struct PassDetail {
+    pub selected: usize,
+    pub pass: Option<TLPass>,
+    pub plan: Vec<PassSample>,
+    pub step_index: usize,
+    pub table_state: TableState,
+    pub current_message: CurrentMsg,
+    pub return_state: AppState,
+}
+
+impl Default for PassDetail {
+    fn default() -> Self {
+        PassDetail {
+            selected: 0,
+            pass: None,
+            plan: vec![],
+            step_index: 2,
+            table_state: TableState::default(),
+            current_message: CurrentMsg::message(""),
+            return_state: AppState::Base,
+        }
+    }
+}
+
+impl PassDetail {
+    pub fn step(&self) -> i64 {
+        PLAN_STEPS[self.step_index % PLAN_STEPS.len()]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Projection {
+    Equirectangular,
+    AzimuthalEquidistant,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MapFollow {
+    Free,
+    Station,
+    Satellite,
+}
+
+pub struct MapSettings {
+    pub show_footprint: bool,
+    pub footprint_elevation: f64,
+    pub show_station_rings: bool,
+    pub show_terminator: bool,
+    pub show_twilight: bool,
+    pub projection: Projection,
+    pub follow: MapFollow,
+    pub zoom: f64,
+    pub pan: (f64, f64),
+    pub central_meridian: f64,
+    pub focus_station: usize,
+    /// (lat, long) last clicked on the map
+    pub cursor: Option<(f64, f64)>,
+}
+
+impl Default for MapSettings {
+    fn default() -> Self {
+        MapSettings {
+            show_footprint: true,
+            footprint_elevation: 0.0,
+            show_station_rings: true,
+            show_terminator: true,
+            show_twilight: false,
+            projection: Projection::Equirectangular,
+            follow: MapFollow::Free,
+            zoom: 1.0,
+            pan: (0.0, 0.0),
+            central_meridian: 0.0,
+            focus_station: 0,
+            cursor: None,
+        }
+    }
+}
+
+/// Where the clickable parts of the screen were drawn in the last frame, for mapping mouse events back to them
+#[derive(Default)]
+pub struct ScreenLayout {
+    /// Size of the frame, used to scale browser mouse events from pixels to cells
+    #[cfg(target_arch = "wasm32")]
+    pub screen: Rect,
+    pub map: Rect,
+    /// Rows of the upcoming pass list with the pass index they select
+    pub passes: Vec<(Rect, usize)>,
+    /// Rows of the satellite list with the entry index they select
+    pub satellites: Vec<(Rect, usize)>,
+    /// Rows of the ground station table with the row index they select
+    pub stations: Vec<(Rect, usize)>,
+}
+
 pub struct Model {
     pub current_satellite: Option<TLSatellite>,
+    /// Celestial body tracked instead of a satellite
+    pub target: Option<CelestialBody>,
+    /// Group whose other members are drawn on the map alongside the current satellite
+    pub tracked_group: Option<SatGroup>,
     pub station_config: GSconfiguration,
     pub sat_config: SatSelection,
     pub upcoming_passes: Vec<TLPass>,
+    pub pass_window_start: DateTime<Utc>,
     pub current_state: AppState,
     pub sub_point_range: i64,
+    pub ground_track: TrackCache,
+    pub coastlines: CoastlineCache,
+    pub layout: RefCell<ScreenLayout>,
+    pub clock: Clock,
+    pub map: MapSettings,
+    pub pass_detail: PassDetail,
+    pub schedule: Schedule,
+    pub alerts: Alerts,
+    pub hooks: Hooks,
+    pub event_log_state: ListState,
+    pub history: History,
+    pub link: LinkView,
+    pub conjunctions: Conjunctions,
+    /// Show the TLE elements in place of the satellite telemetry
+    pub show_elements: bool,
+    pub keymap: Keymap,
+    pub help: HelpView,
+    pub theme: Theme,
     pub exit: bool,
 }
 
@@ -263,8 +605,28 @@ impl Default for Model {
         Model {
             station_config: GSconfiguration::default(),
             current_satellite: None,
+            target: None,
+            tracked_group: None,
             upcoming_passes: vec![],
+            pass_window_start: Utc::now(),
             sub_point_range: 120 * 60,
+            ground_track: TrackCache::default(),
+            coastlines: CoastlineCache::default(),
+            layout: RefCell::new(ScreenLayout::default()),
+            clock: Clock::default(),
+            map: MapSettings::default(),
+            pass_detail: PassDetail::default(),
+            schedule: Schedule::default(),
+            alerts: Alerts::default(),
+            hooks: Hooks::default(),
+            event_log_state: ListState::default(),
+            history: History::default(),
+            link: LinkView::default(),
+            conjunctions: Conjunctions::default(),
+            show_elements: false,
+            keymap: Keymap::default(),
+            help: HelpView::default(),
+            theme: Theme::default(),
             exit: false,
             sat_config: SatSelection::default(),
             current_state: AppState::Base,
@@ -292,12 +654,20 @@ pub struct MetaData {
 pub struct TLGroundStation {
     pub station: GroundStation,
     pub active: bool,
+    #[serde(default)]
+    pub elevation_mask: f64,
+    /// Hours from UTC of the station's civil time, local mean time is shown if unset
+    #[serde(default)]
+    pub utc_offset: Option<f64>,
 }
 
 #[derive(Debug, Clone)]
 pub struct TLPass {
     pub pass: Pass,
     pub station: GroundStation,
+    pub norad_id: u64,
+    pub alerts: AlertState,
+    pub sun: SunApproach,
 }
 
 pub mod celestrak_date {
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::app::file_cache::cache::get_theme;
+
+/// Built-in themes a theme file can start from
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeName {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+    Monochrome,
+}
+
+/// Contents of `theme.json`: a built-in theme and any colours to change in it, by slot name
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeFile {
+    pub theme: ThemeName,
+    pub colors: HashMap<String, String>,
+}
+
+/// Colours used across the UI, by what they mark rather than by hue
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub map: Color,
+    pub track_past: Color,
+    pub track_future: Color,
+    pub node: Color,
+    pub station: Color,
+    pub satellite: Color,
+    pub group: Color,
+    pub sun: Color,
+    pub moon: Color,
+    pub cursor: Color,
+    pub twilight_civil: Color,
+    pub twilight_nautical: Color,
+    pub twilight_astronomical: Color,
+    pub key: Color,
+    pub highlight: Color,
+    pub error: Color,
+    pub good: Color,
+    pub warning: Color,
+    pub muted: Color,
+    pub panel: Color,
+    pub border: Color,
+    pub alert_text: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        if no_color() {
+            return Theme::built_in(ThemeName::Monochrome);
+        }
+        match get_theme() {
+            Ok(x) => Theme::from_file(&x),
+            Err(x) => {
+                warn!("Unable to load theme, using the dark theme: {}", x);
+                Theme::built_in(ThemeName::Dark)
+            }
+        }
+    }
+}
+
+impl Theme {
+    pub fn built_in(name: ThemeName) -> Theme {
+        match name {
+            ThemeName::Dark => Theme {
+                map: Color::Green,
+                track_past: Color::Blue,
+                track_future: Color::Cyan,
+                node: Color::Magenta,
+                station: Color::Yellow,
+                satellite: Color::Red,
+                group: Color::LightMagenta,
+                sun: Color::LightYellow,
+                moon: Color::White,
+                cursor: Color::White,
+                twilight_civil: Color::Gray,
+                twilight_nautical: Color::Blue,
+                twilight_astronomical: Color::DarkGray,
+                key: Color::Blue,
+                highlight: Color::Yellow,
+                error: Color::Red,
+                good: Color::Green,
+                warning: Color::Yellow,
+                muted: Color::DarkGray,
+                panel: Color::DarkGray,
+                border: Color::Cyan,
+                alert_text: Color::White,
+            },
+            ThemeName::Light => Theme {
+                map: Color::Green,
+                track_past: Color::DarkGray,
+                track_future: Color::Blue,
+                node: Color::Magenta,
+                station: Color::Indexed(130),
+                satellite: Color::Red,
+                group: Color::Magenta,
+                sun: Color::Indexed(136),
+                moon: Color::DarkGray,
+                cursor: Color::Black,
+                twilight_civil: Color::Gray,
+                twilight_nautical: Color::Blue,
+                twilight_astronomical: Color::DarkGray,
+                key: Color::Blue,
+                highlight: Color::Indexed(130),
+                error: Color::Red,
+                good: Color::Green,
+                warning: Color::Indexed(130),
+                muted: Color::DarkGray,
+                panel: Color::Gray,
+                border: Color::Blue,
+                alert_text: Color::White,
+            },
+            ThemeName::HighContrast => Theme {
+                map: Color::White,
+                track_past: Color::LightBlue,
+                track_future: Color::LightCyan,
+                node: Color::LightMagenta,
+                station: Color::LightYellow,
+                satellite: Color::LightRed,
+                group: Color::LightMagenta,
+                sun: Color::LightYellow,
+                moon: Color::White,
+                cursor: Color::White,
+                twilight_civil: Color::White,
+                twilight_nautical: Color::LightBlue,
+                twilight_astronomical: Color::Gray,
+                key: Color::LightCyan,
+                highlight: Color::LightYellow,
+                error: Color::LightRed,
+                good: Color::LightGreen,
+                warning: Color::LightYellow,
+                muted: Color::Gray,
+                panel: Color::Black,
+                border: Color::White,
+                alert_text: Color::Black,
+            },
+            // Leaves everything in the terminal's own colours, relying on bold, reversed and underlined text
+            ThemeName::Monochrome => Theme {
+                map: Color::Reset,
+                track_past: Color::Reset,
+                track_future: Color::Reset,
+                node: Color::Reset,
+                station: Color::Reset,
+                satellite: Color::Reset,
+                group: Color::Reset,
+                sun: Color::Reset,
+                moon: Color::Reset,
+                cursor: Color::Reset,
+                twilight_civil: Color::Reset,
+                twilight_nautical: Color::Reset,
+                twilight_astronomical: Color::Reset,
+                key: Color::Reset,
+                highlight: Color::Reset,
+                error: Color::Reset,
+                good: Color::Reset,
+                warning: Color::Reset,
+                muted: Color::Reset,
+                panel: Color::Reset,
+                border: Color::Reset,
+                alert_text: Color::Reset,
+            },
+        }
+    }
+
+    /// The file's built-in theme with its colours changed. Unknown slots and colours are skipped.
+    pub fn from_file(file: &ThemeFile) -> Theme {
+        let mut theme = Theme::built_in(file.theme);
+        for (slot, value) in &file.colors {
+            match Color::from_str(value) {
+                Ok(color) => {
+                    if !theme.set(slot, color) {
+                        warn!("Theme: unknown colour slot {:?}", slot);
+                    }
+                }
+                Err(_) => warn!("Theme: unknown colour {:?} for {}", value, slot),
+            }
+        }
+        theme
+    }
+
+    fn set(&mut self, slot: &str, color: Color) -> bool {
+        let field = match slot {
+            "map" => &mut self.map,
+            "track_past" => &mut self.track_past,
+            "track_future" => &mut self.track_future,
+            "node" => &mut self.node,
+            "station" => &mut self.station,
+            "satellite" => &mut self.satellite,
+            "group" => &mut self.group,
+            "sun" => &mut self.sun,
+            "moon" => &mut self.moon,
+            "cursor" => &mut self.cursor,
+            "twilight_civil" => &mut self.twilight_civil,
+            "twilight_nautical" => &mut self.twilight_nautical,
+            "twilight_astronomical" => &mut self.twilight_astronomical,
+            "key" => &mut self.key,
+            "highlight" => &mut self.highlight,
+            "error" => &mut self.error,
+            "good" => &mut self.good,
+            "warning" => &mut self.warning,
+            "muted" => &mut self.muted,
+            "panel" => &mut self.panel,
+            "border" => &mut self.border,
+            "alert_text" => &mut self.alert_text,
+            _ => return false,
+        };
+        *field = color;
+        true
+    }
+}
+
+/// Whether the `NO_COLOR` convention (<https://no-color.org>) asks for no colour
+#[cfg(not(target_arch = "wasm32"))]
+fn no_color() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|x| !x.is_empty())
+}
+
+/// The browser has no environment, so colour is only turned off through the theme
+#[cfg(target_arch = "wasm32")]
+fn no_color() -> bool {
+    false
+}
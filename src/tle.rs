@@ -0,0 +1,571 @@
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use color_eyre::{Result, eyre::eyre};
+
+use crate::geometry::EARTH_RADIUS;
+
+/// Earth's gravitational parameter in km^3/s^2
+const MU: f64 = 398_600.441_8;
+
+/// Earth's second zonal harmonic
+const J2: f64 = 0.001_082_63;
+
+/// Nodal precession (deg/day) of a sun-synchronous orbit
+const SUN_SYNCHRONOUS_RATE: f64 = 0.985_6;
+
+/// Mean elements of a TLE, angles in degrees and mean motion in rev/day
+#[derive(Debug, Clone, Copy)]
+pub struct Elements {
+    pub epoch: DateTime<Utc>,
+    /// First derivative of mean motion divided by two, rev/day^2
+    pub mean_motion_dot: f64,
+    /// Second derivative of mean motion divided by six, rev/day^3
+    pub mean_motion_ddot: f64,
+    /// 1/earth radii
+    pub bstar: f64,
+    pub element_set: u64,
+    pub inclination: f64,
+    pub right_ascension: f64,
+    pub eccentricity: f64,
+    pub argument_of_perigee: f64,
+    pub mean_anomaly: f64,
+    pub mean_motion: f64,
+    pub revolution_number: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OrbitClass {
+    Leo,
+    Sso,
+    Meo,
+    Geo,
+    /// Near-circular above GEO altitude but not geosynchronous, e.g. graveyard orbits
+    HighCircular,
+    Heo,
+}
+
+impl OrbitClass {
+    pub fn name(&self) -> &'static str {
+        match self {
+            OrbitClass::Leo => "LEO",
+            OrbitClass::Sso => "SSO",
+            OrbitClass::Meo => "MEO",
+            OrbitClass::Geo => "GEO",
+            OrbitClass::HighCircular => "High circular",
+            OrbitClass::Heo => "HEO",
+        }
+    }
+}
+
+impl Elements {
+    pub fn parse(tle: &str) -> Result<Elements> {
+        let line = |number: &str| {
+            tle.lines()
+                .map(str::trim)
+                .find(|x| x.starts_with(number))
+                .ok_or_else(|| eyre!("TLE has no line {}", number.trim()))
+        };
+        let (line_1, line_2) = (line("1 ")?, line("2 ")?);
+        Ok(Elements {
+            epoch: epoch(field(line_1, 19, 20)?, field(line_1, 21, 32)?)?,
+            mean_motion_dot: field(line_1, 34, 43)?.parse()?,
+            mean_motion_ddot: implied_decimal(field(line_1, 45, 52)?)?,
+            bstar: implied_decimal(field(line_1, 54, 61)?)?,
+            element_set: field(line_1, 65, 68)?.parse()?,
+            inclination: field(line_2, 9, 16)?.parse()?,
+            right_ascension: field(line_2, 18, 25)?.parse()?,
+            eccentricity: format!("0.{}", field(line_2, 27, 33)?).parse()?,
+            argument_of_perigee: field(line_2, 35, 42)?.parse()?,
+            mean_anomaly: field(line_2, 44, 51)?.parse()?,
+            mean_motion: field(line_2, 53, 63)?.parse()?,
+            revolution_number: field(line_2, 64, 68)?.parse()?,
+        })
+    }
+
+    /// Semi-major axis (km) from the mean motion
+    pub fn semi_major_axis(&self) -> f64 {
+        let mean_motion = self.mean_motion * std::f64::consts::TAU / 86400.0;
+        (MU / (mean_motion * mean_motion)).cbrt()
+    }
+
+    /// Secular drift of the ascending node (deg/day) due to J2
+    pub fn nodal_precession(&self) -> f64 {
+        let semi_latus_rectum = self.semi_major_axis() * (1.0 - self.eccentricity.powi(2));
+        -1.5 * self.mean_motion
+            * 360.0
+            * J2
+            * (EARTH_RADIUS / semi_latus_rectum).powi(2)
+            * self.inclination.to_radians().cos()
+    }
+
+    pub fn orbit_class(&self) -> OrbitClass {
+        let altitude = self.semi_major_axis() - EARTH_RADIUS;
+        if self.eccentricity > 0.25 {
+            OrbitClass::Heo
+        } else if (self.mean_motion - 1.0027).abs() < 0.01 && self.eccentricity < 0.01 {
+            OrbitClass::Geo
+        } else if altitude < 2000.0 && (self.nodal_precession() - SUN_SYNCHRONOUS_RATE).abs() < 0.1
+        {
+            OrbitClass::Sso
+        } else if altitude < 2000.0 {
+            OrbitClass::Leo
+        } else if altitude < 35_786.0 {
+            OrbitClass::Meo
+        } else {
+            OrbitClass::HighCircular
+        }
+    }
+
+    /// Revolutions (counted from the ascending node, as the TLE does) at `seconds` after the TLE epoch
+    pub fn revolution_at(&self, seconds: f64) -> f64 {
+        let argument_of_latitude =
+            (self.argument_of_perigee + true_anomaly(self.mean_anomaly, self.eccentricity)) / 360.0;
+        self.revolution_number as f64
+            + argument_of_latitude.rem_euclid(1.0)
+            + seconds * self.mean_motion / 86400.0
+    }
+}
+
+/// Trimmed contents of a TLE field, given by its 1-indexed inclusive columns
+fn field(line: &str, start: usize, end: usize) -> Result<&str> {
+    line.get(start - 1..end.min(line.len()))
+        .map(str::trim)
+        .ok_or_else(|| eyre!("TLE line is too short for columns {}-{}", start, end))
+}
+
+/// A problem with one field of a TLE, with its 1-indexed line and columns
+#[derive(Debug, Clone)]
+pub struct TleError {
+    pub line: usize,
+    pub columns: (usize, usize),
+    pub field: &'static str,
+    pub message: String,
+}
+
+impl std::fmt::Display for TleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.columns.0 == self.columns.1 {
+            write!(f, "Line {} col {}", self.line, self.columns.0)?;
+        } else {
+            write!(
+                f,
+                "Line {} cols {}-{}",
+                self.line, self.columns.0, self.columns.1
+            )?;
+        }
+        write!(f, " ({}): {}", self.field, self.message)
+    }
+}
+
+/// Columns that must be blank on each data line, 1-indexed
+const SEPARATORS: [&[usize]; 2] = [&[2, 9, 18, 33, 44, 53, 62, 64], &[2, 8, 17, 26, 34, 43, 52]];
+
+/// Length of each data line of a TLE
+const LINE_LENGTH: usize = 69;
+
+/// Data line index, columns, field name and the result of checking it
+type FieldCheck = (usize, (usize, usize), &'static str, Result<(), String>);
+
+/// Checks a 2 or 3 line TLE field by field, returning every problem found
+pub fn validate_tle(tle: &str) -> Vec<TleError> {
+    let lines: Vec<&str> = tle
+        .lines()
+        .map(str::trim_end)
+        .filter(|x| !x.is_empty())
+        .collect();
+    let (offset, data) = match lines.len() {
+        2 => (0, [lines[0], lines[1]]),
+        3 => (1, [lines[1], lines[2]]),
+        x => {
+            return vec![TleError {
+                line: 1,
+                columns: (1, 1),
+                field: "TLE",
+                message: format!("expected 2 or 3 lines, found {}", x),
+            }];
+        }
+    };
+    let mut errors = vec![];
+    if offset == 1 && lines[0].trim_start_matches("0 ").trim().is_empty() {
+        errors.push(TleError {
+            line: 1,
+            columns: (1, 1),
+            field: "name",
+            message: "is blank".to_string(),
+        });
+    }
+    let mut error = |index: usize, columns: (usize, usize), field, message: String| {
+        errors.push(TleError {
+            line: index + offset + 1,
+            columns,
+            field,
+            message,
+        })
+    };
+    let mut malformed = false;
+    for (index, line) in data.iter().enumerate() {
+        if !line.is_ascii() {
+            malformed = true;
+            error(
+                index,
+                (1, LINE_LENGTH),
+                "line",
+                "contains non-ASCII characters".to_string(),
+            );
+            continue;
+        }
+        if line.len() != LINE_LENGTH {
+            malformed = true;
+            error(
+                index,
+                (1, line.len()),
+                "line",
+                format!(
+                    "is {} characters long, expected {}",
+                    line.len(),
+                    LINE_LENGTH
+                ),
+            );
+            continue;
+        }
+        let expected = (b'1' + index as u8) as char;
+        if !line.starts_with(expected) {
+            error(
+                index,
+                (1, 1),
+                "line number",
+                format!("expected {}", expected),
+            );
+        }
+        for column in SEPARATORS[index] {
+            if line.as_bytes()[column - 1] != b' ' {
+                error(
+                    index,
+                    (*column, *column),
+                    "separator",
+                    "expected a space".to_string(),
+                );
+            }
+        }
+        if let Err(x) = catalog_number(&line[2..7]) {
+            error(index, (3, 7), "catalogue number", x);
+        }
+        let sum = checksum(&line[..68]);
+        let given = line.as_bytes()[68];
+        if given != b'0' + sum {
+            error(
+                index,
+                (69, 69),
+                "checksum",
+                format!("is {}, computed {}", given as char, sum),
+            );
+        }
+    }
+    if malformed {
+        return errors;
+    }
+    let [line_1, line_2] = data;
+    if line_1[2..7] != line_2[2..7] {
+        error(
+            1,
+            (3, 7),
+            "catalogue number",
+            format!(
+                "{} does not match line 1 ({})",
+                line_2[2..7].trim(),
+                line_1[2..7].trim()
+            ),
+        );
+    }
+    if !matches!(&line_1[7..8], "U" | "C" | "S" | " ") {
+        error(
+            0,
+            (8, 8),
+            "classification",
+            "expected U, C or S".to_string(),
+        );
+    }
+    let number = |text: &str, min: f64, max: f64| -> Result<f64, String> {
+        let value: f64 = text
+            .trim()
+            .parse()
+            .map_err(|_| format!("'{}' is not a number", text.trim()))?;
+        if value < min || value > max {
+            return Err(format!("{} is outside {}-{}", value, min, max));
+        }
+        Ok(value)
+    };
+    let digits = |text: &str| -> Result<(), String> {
+        if text.trim().chars().all(|x| x.is_ascii_digit()) {
+            Ok(())
+        } else {
+            Err(format!("'{}' is not an integer", text.trim()))
+        }
+    };
+    let checks: [FieldCheck; 15] = [
+        (
+            0,
+            (19, 20),
+            "epoch year",
+            digits(&line_1[18..20]).and_then(|_| {
+                if line_1[18..20].trim().len() == 2 {
+                    Ok(())
+                } else {
+                    Err("expected two digits".to_string())
+                }
+            }),
+        ),
+        (
+            0,
+            (21, 32),
+            "epoch day",
+            number(&line_1[20..32], 1.0, 367.0).map(|_| ()),
+        ),
+        (
+            0,
+            (34, 43),
+            "mean motion 1st derivative",
+            number(&line_1[33..43], -1.0, 1.0).map(|_| ()),
+        ),
+        (
+            0,
+            (45, 52),
+            "mean motion 2nd derivative",
+            implied_decimal(line_1[44..52].trim())
+                .map(|_| ())
+                .map_err(|x| x.to_string()),
+        ),
+        (
+            0,
+            (54, 61),
+            "BSTAR",
+            implied_decimal(line_1[53..61].trim())
+                .map(|_| ())
+                .map_err(|x| x.to_string()),
+        ),
+        (0, (63, 63), "ephemeris type", digits(&line_1[62..63])),
+        (0, (65, 68), "element set number", digits(&line_1[64..68])),
+        (
+            1,
+            (9, 16),
+            "inclination",
+            number(&line_2[8..16], 0.0, 180.0).map(|_| ()),
+        ),
+        (
+            1,
+            (18, 25),
+            "RAAN",
+            number(&line_2[17..25], 0.0, 360.0).map(|_| ()),
+        ),
+        (
+            1,
+            (27, 33),
+            "eccentricity",
+            digits(&line_2[26..33]).and_then(|_| {
+                if line_2[26..33].trim().is_empty() {
+                    Err("is blank".to_string())
+                } else {
+                    Ok(())
+                }
+            }),
+        ),
+        (
+            1,
+            (35, 42),
+            "argument of perigee",
+            number(&line_2[34..42], 0.0, 360.0).map(|_| ()),
+        ),
+        (
+            1,
+            (44, 51),
+            "mean anomaly",
+            number(&line_2[43..51], 0.0, 360.0).map(|_| ()),
+        ),
+        (
+            1,
+            (53, 63),
+            "mean motion",
+            number(&line_2[52..63], 0.0, 20.0).and_then(|x| {
+                if x > 0.0 {
+                    Ok(())
+                } else {
+                    Err("must be positive".to_string())
+                }
+            }),
+        ),
+        (1, (64, 68), "revolution number", digits(&line_2[63..68])),
+        (
+            0,
+            (10, 17),
+            "international designator",
+            if line_1[9..17]
+                .chars()
+                .all(|x| x.is_ascii_alphanumeric() || x == ' ')
+            {
+                Ok(())
+            } else {
+                Err(format!("'{}' is not a designator", line_1[9..17].trim()))
+            },
+        ),
+    ];
+    for (index, columns, field, result) in checks {
+        if let Err(x) = result {
+            error(index, columns, field, x);
+        }
+    }
+    errors.sort_by_key(|x| (x.line, x.columns.0));
+    errors
+}
+
+/// Mod 10 checksum of a TLE line: the sum of its digits, with each minus sign counting as 1
+fn checksum(line: &str) -> u8 {
+    let sum: u32 = line
+        .chars()
+        .map(|x| match x {
+            '-' => 1,
+            x => x.to_digit(10).unwrap_or(0),
+        })
+        .sum();
+    (sum % 10) as u8
+}
+
+/// Checks a catalogue number, allowing the Alpha-5 form for numbers past 99999
+fn catalog_number(text: &str) -> Result<(), String> {
+    let mut chars = text.trim_start().chars();
+    let first = chars.next().unwrap_or(' ');
+    let valid_first =
+        first.is_ascii_digit() || (first.is_ascii_uppercase() && first != 'I' && first != 'O');
+    if valid_first && chars.all(|x| x.is_ascii_digit()) {
+        Ok(())
+    } else {
+        Err(format!("'{}' is not a catalogue number", text.trim()))
+    }
+}
+
+/// Epoch from the two digit year and fractional day of year
+fn epoch(year: &str, day: &str) -> Result<DateTime<Utc>> {
+    let year: i32 = year.parse()?;
+    let day: f64 = day.parse()?;
+    let year = if year < 57 { 2000 + year } else { 1900 + year };
+    let start = NaiveDate::from_yo_opt(year, 1)
+        .and_then(|x| x.and_hms_opt(0, 0, 0))
+        .ok_or_else(|| eyre!("Invalid TLE epoch year {}", year))?
+        .and_utc();
+    Ok(start + TimeDelta::milliseconds(((day - 1.0) * 86_400_000.0).round() as i64))
+}
+
+/// Value of a TLE field in assumed decimal point notation, e.g. `-11606-4` for -0.11606e-4
+fn implied_decimal(text: &str) -> Result<f64> {
+    let (sign, text) = match text.strip_prefix('-') {
+        Some(x) => (-1.0, x),
+        None => (1.0, text.trim_start_matches('+')),
+    };
+    let split = text
+        .rfind(['-', '+'])
+        .ok_or_else(|| eyre!("TLE field {} has no exponent", text))?;
+    let mantissa: f64 = format!("0.{}", text[..split].trim()).parse()?;
+    let exponent: i32 = text[split..].parse()?;
+    Ok(sign * mantissa * 10f64.powi(exponent))
+}
+
+/// True anomaly (deg) from mean anomaly (deg), solving Kepler's equation by Newton iteration
+pub fn true_anomaly(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    let mean_anomaly = mean_anomaly.to_radians();
+    let mut eccentric_anomaly = mean_anomaly;
+    for _ in 0..10 {
+        eccentric_anomaly -=
+            (eccentric_anomaly - eccentricity * eccentric_anomaly.sin() - mean_anomaly)
+                / (1.0 - eccentricity * eccentric_anomaly.cos());
+    }
+    (2.0 * ((1.0 + eccentricity).sqrt() * (eccentric_anomaly / 2.0).sin())
+        .atan2((1.0 - eccentricity).sqrt() * (eccentric_anomaly / 2.0).cos()))
+    .to_degrees()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAME: &str = "ISS (ZARYA)";
+    const LINE_1: &str = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    const LINE_2: &str = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+
+    /// The line with its checksum recomputed after editing it
+    fn with_checksum(line: &str) -> String {
+        format!("{}{}", &line[..68], checksum(&line[..68]))
+    }
+
+    fn fields(errors: &[TleError]) -> Vec<(usize, &'static str)> {
+        errors.iter().map(|x| (x.line, x.field)).collect()
+    }
+
+    #[test]
+    fn accepts_two_and_three_line_tles() {
+        assert!(validate_tle(&format!("{}\n{}", LINE_1, LINE_2)).is_empty());
+        assert!(validate_tle(&format!("{}\n{}\n{}\n", NAME, LINE_1, LINE_2)).is_empty());
+        let elements = Elements::parse(&format!("{}\n{}", LINE_1, LINE_2)).unwrap();
+        assert_eq!(elements.revolution_number, 56353);
+        assert!((elements.eccentricity - 0.000_670_3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rejects_bad_checksum() {
+        let line_1 = format!("{}8", &LINE_1[..68]);
+        let errors = validate_tle(&format!("{}\n{}\n{}", NAME, line_1, LINE_2));
+        assert_eq!(fields(&errors), vec![(2, "checksum")]);
+        assert_eq!(errors[0].columns, (69, 69));
+    }
+
+    #[test]
+    fn rejects_mismatched_catalog_numbers() {
+        let line_2 = with_checksum(&LINE_2.replacen("25544", "25545", 1));
+        let errors = validate_tle(&format!("{}\n{}", LINE_1, line_2));
+        assert_eq!(fields(&errors), vec![(2, "catalogue number")]);
+    }
+
+    #[test]
+    fn rejects_short_line() {
+        let errors = validate_tle(&format!("{}\n{}", &LINE_1[..60], LINE_2));
+        assert_eq!(fields(&errors), vec![(1, "line")]);
+        assert_eq!(errors[0].columns, (1, 60));
+    }
+
+    #[test]
+    fn rejects_non_ascii_line() {
+        let line_2 = LINE_2.replacen("51.6416", "51.6416é", 1);
+        let errors = validate_tle(&format!("{}\n{}", LINE_1, &line_2));
+        assert_eq!(fields(&errors), vec![(2, "line")]);
+        assert_eq!(errors[0].message, "contains non-ASCII characters");
+    }
+
+    #[test]
+    fn rejects_wrong_line_count() {
+        assert_eq!(fields(&validate_tle(LINE_1)), vec![(1, "TLE")]);
+    }
+
+    #[test]
+    fn accepts_alpha_5_catalog_numbers() {
+        let alpha_5 = |line: &str, id: &str| with_checksum(&line.replacen("25544", id, 1));
+        let tle = format!("{}\n{}", alpha_5(LINE_1, "A5544"), alpha_5(LINE_2, "A5544"));
+        assert!(validate_tle(&tle).is_empty());
+        assert!(catalog_number("Z9999").is_ok());
+        assert!(catalog_number("I0001").is_err());
+        assert!(catalog_number("O0001").is_err());
+        assert!(catalog_number("a0001").is_err());
+        assert!(catalog_number("1A001").is_err());
+    }
+
+    #[test]
+    fn reads_implied_decimals() {
+        assert!((implied_decimal("-11606-4").unwrap() + 0.116_06e-4).abs() < 1e-15);
+        assert_eq!(implied_decimal("00000+0").unwrap(), 0.0);
+        assert!((implied_decimal("12345-3").unwrap() - 0.123_45e-3).abs() < 1e-15);
+        assert!(implied_decimal("12345").is_err());
+    }
+
+    #[test]
+    fn checksums_count_minus_signs_as_one() {
+        assert_eq!(checksum(&LINE_1[..68]), 7);
+        assert_eq!(checksum(&LINE_2[..68]), 7);
+        assert_eq!(checksum("1 -- 2"), 5);
+    }
+}
@@ -1,12 +1,18 @@
 use core::panic;
 
+use chrono::Utc;
 use infobox::view_sat_data;
-use popup::{view_popup_gs_config, view_popup_sat_config};
+use popup::{
+    view_popup_conjunctions, view_popup_event_log, view_popup_gs_config, view_popup_help,
+    view_popup_history, view_popup_link_budget, view_popup_pass_detail, view_popup_sat_config,
+    view_popup_schedule,
+};
 use ratatui::{
     Frame,
     layout::{Constraint, Layout, Rect},
-    style::Stylize,
+    style::{Style, Stylize},
     text::Line,
+    widgets::{Block, Clear, Paragraph},
 };
 use topbar::view_top_bar;
 use track::view_ground_track;
@@ -14,9 +20,15 @@ mod infobox;
 mod popup;
 mod topbar;
 mod track;
-use crate::structs::{AppState, Model};
+use crate::keymap::{Action, KeyContext};
+use crate::structs::{AppState, Model, ScreenLayout};
 
 pub fn view(model: &Model, frame: &mut Frame) {
+    *model.layout.borrow_mut() = ScreenLayout::default();
+    #[cfg(target_arch = "wasm32")]
+    {
+        model.layout.borrow_mut().screen = frame.area();
+    }
     {
         let [top_bar, core_bar, bottom_bar] = Layout::vertical([
             Constraint::Length(5),
@@ -30,18 +42,74 @@ pub fn view(model: &Model, frame: &mut Frame) {
             Layout::horizontal([Constraint::Fill(1), Constraint::Length(41)]).areas(core_bar);
         view_ground_track(model, frame, Some(ground_track_area));
         view_sat_data(model, frame, Some(sat_stat_area));
+        view_alert_banner(model, frame, ground_track_area);
     }
     {
-        if model.current_state == AppState::SatSelect
-            || model.current_state == AppState::SatAddition
-        {
+        // The help popup is drawn over whatever screen it was opened from
+        let state = if model.current_state == AppState::Help {
+            model.help.return_state
+        } else {
+            model.current_state
+        };
+        if state == AppState::SatSelect || state == AppState::SatAddition {
             view_popup_sat_config(model, frame);
-        } else if model.current_state == AppState::GSConfig {
+        } else if state == AppState::GSConfig {
             view_popup_gs_config(model, frame)
+        } else if state == AppState::LinkBudget {
+            view_popup_link_budget(model, frame)
+        } else if state == AppState::History {
+            view_popup_history(model, frame)
+        } else if state == AppState::Conjunctions {
+            view_popup_conjunctions(model, frame)
+        } else if state == AppState::EventLog {
+            view_popup_event_log(model, frame)
+        } else if state == AppState::Schedule {
+            view_popup_schedule(model, frame)
+        } else if state == AppState::PassDetail {
+            if model.pass_detail.return_state == AppState::Schedule {
+                view_popup_schedule(model, frame)
+            }
+            view_popup_pass_detail(model, frame)
+        }
+        if model.current_state == AppState::Help {
+            view_popup_help(model, frame)
         }
     }
 }
 
+fn view_alert_banner(model: &Model, frame: &mut Frame, area: Rect) {
+    let now = Utc::now();
+    let Some(banner) = model.alerts.banner.as_ref().filter(|x| x.expires > now) else {
+        return;
+    };
+    let [banner_area, _] =
+        Layout::vertical([Constraint::Length(3), Constraint::Fill(1)]).areas(area);
+    let [_, banner_area, _] = Layout::horizontal([
+        Constraint::Fill(1),
+        Constraint::Length(banner.text.chars().count() as u16 + 4),
+        Constraint::Fill(1),
+    ])
+    .areas(banner_area);
+    let style = Style::new()
+        .fg(model.theme.alert_text)
+        .bg(model.theme.error)
+        .bold();
+    // Flashes by reversing the style rather than changing colour, so it still flashes under the monochrome theme
+    let style = if now.timestamp_subsec_millis() < 500 {
+        style
+    } else {
+        style.reversed()
+    };
+    frame.render_widget(Clear, banner_area);
+    frame.render_widget(
+        Paragraph::new(banner.text.as_str())
+            .centered()
+            .block(Block::bordered())
+            .style(style),
+        banner_area,
+    );
+}
+
 fn strf_seconds_small(seconds: i64) -> String {
     let working_seconds;
     if seconds < 0 {
@@ -64,17 +132,25 @@ fn view_app_border(model: &Model, frame: &mut Frame, area: Option<Rect>) {
     let draw_area = area.unwrap_or(frame.area());
     match model.current_state {
         AppState::Base => {
-            let instructions = Line::from(vec![
-                "Configure Ground Stations ".into(),
-                "<g> ".blue().bold(),
-                "Configure Satellites ".into(),
-                "<s> ".blue().bold(),
-                "Quit ".into(),
-                "<q> ".blue().bold(),
-            ]);
+            let instructions = hints(
+                model,
+                KeyContext::Base,
+                &[
+                    ("Configure Ground Stations", &[Action::Stations]),
+                    ("Configure Satellites", &[Action::Satellites]),
+                    ("Scrub Time", &[Action::ScrubBack, Action::ScrubForward]),
+                    ("Next AOS", &[Action::NextAos]),
+                    ("Pass Detail", &[Action::Select]),
+                    ("Schedule", &[Action::Schedule]),
+                    ("Conjunctions", &[Action::Conjunctions]),
+                    ("Elements", &[Action::Elements]),
+                    ("Quit", &[Action::Close]),
+                    ("Help", &[Action::Help]),
+                ],
+            );
             frame.render_widget(instructions.right_aligned(), draw_area);
         }
-        x => match_similar(frame, &x, draw_area),
+        _ => match_similar(frame, model, draw_area),
     }
 }
 #[cfg(target_arch = "wasm32")]
@@ -82,54 +158,168 @@ fn view_app_border(model: &Model, frame: &mut Frame, area: Option<Rect>) {
     let draw_area = area.unwrap_or(frame.area());
     match model.current_state {
         AppState::Base => {
-            let instructions = Line::from(vec![
-                "Configure Ground Stations ".into(),
-                "<g> ".blue().bold(),
-                "Configure Satellites ".into(),
-                "<s> ".blue().bold(),
-            ]);
+            let instructions = hints(
+                model,
+                KeyContext::Base,
+                &[
+                    ("Configure Ground Stations", &[Action::Stations]),
+                    ("Configure Satellites", &[Action::Satellites]),
+                    ("Scrub Time", &[Action::ScrubBack, Action::ScrubForward]),
+                    ("Next AOS", &[Action::NextAos]),
+                    ("Pass Detail", &[Action::Select]),
+                    ("Schedule", &[Action::Schedule]),
+                    ("Conjunctions", &[Action::Conjunctions]),
+                    ("Elements", &[Action::Elements]),
+                    ("Help", &[Action::Help]),
+                ],
+            );
             frame.render_widget(instructions.right_aligned(), draw_area);
         }
         AppState::SatWaitingFetch => {
-            let instructions = Line::from(vec!["".into(), "".blue().bold()]);
+            let instructions = Line::from(vec!["".into(), "".fg(model.theme.key).bold()]);
             frame.render_widget(instructions.right_aligned(), draw_area);
         }
-        x => match_similar(frame, &x, draw_area),
+        _ => match_similar(frame, model, draw_area),
     }
 }
-fn match_similar(frame: &mut Frame, state: &AppState, area: Rect) {
-    match state {
-        AppState::SatSelect => {
-            let instruction = Line::from(vec![
-                "Fetch TLE from Spacetrack ".into(),
-                "<f> ".blue().bold(),
-                "Copy TLE ".into(),
-                "<c> ".blue().bold(),
-                "Close Popup ".into(),
-                "<q> ".blue().bold(),
-            ]);
-            frame.render_widget(instruction.right_aligned(), area);
-        }
-        AppState::SatAddition => {
-            let instruction = Line::from(vec![
-                "Paste TLE ".into(),
-                "<v> ".blue().bold(),
-                "Close Popup ".into(),
-                "<q> ".blue().bold(),
-            ]);
-            frame.render_widget(instruction.right_aligned(), area);
-        }
-        AppState::GSConfig => {
-            let instruction = Line::from(vec![
-                "Activate Station ".into(),
-                "<a>".blue().bold(),
-                "Close Popup ".into(),
-                "<q>".blue().bold(),
-            ]);
-            frame.render_widget(instruction.right_aligned(), area);
-        }
+fn match_similar(frame: &mut Frame, model: &Model, area: Rect) {
+    let instruction = match model.current_state {
+        AppState::SatSelect => hints(
+            model,
+            KeyContext::SatSelect,
+            &[
+                ("Search", &[Action::Search]),
+                ("Sort", &[Action::Sort]),
+                ("Fetch TLE from Spacetrack", &[Action::Fetch]),
+                ("Copy TLE", &[Action::Copy]),
+                ("Favourite", &[Action::Favourite]),
+                ("Groups", &[Action::Groups]),
+                ("Export Group", &[Action::Export]),
+                ("Close Popup", &[Action::Close]),
+                ("Help", &[Action::Help]),
+            ],
+        ),
+        AppState::SatAddition => hints(
+            model,
+            KeyContext::SatAddition,
+            &[
+                ("Paste TLE", &[Action::Paste]),
+                ("Close Popup", &[Action::Close]),
+                ("Help", &[Action::Help]),
+            ],
+        ),
+        AppState::GSConfig => hints(
+            model,
+            KeyContext::GSConfig,
+            &[
+                ("Activate Station", &[Action::Activate]),
+                ("Close Popup", &[Action::Close]),
+                ("Help", &[Action::Help]),
+            ],
+        ),
+        AppState::PassDetail => hints(
+            model,
+            KeyContext::PassDetail,
+            &[
+                ("Change Step", &[Action::Step]),
+                ("Export", &[Action::Export]),
+                ("Copy Table", &[Action::Copy]),
+                ("Link Budget", &[Action::LinkBudget]),
+                ("Close Popup", &[Action::Close]),
+                ("Help", &[Action::Help]),
+            ],
+        ),
+        AppState::LinkBudget => hints(
+            model,
+            KeyContext::LinkBudget,
+            &[("Close Popup", &[Action::Close]), ("Help", &[Action::Help])],
+        ),
+        AppState::History => hints(
+            model,
+            KeyContext::History,
+            &[
+                ("Edit Notes", &[Action::Edit]),
+                ("Outcome", &[Action::Outcome]),
+                ("Export", &[Action::Export]),
+                ("Copy Log", &[Action::Copy]),
+                ("Close Popup", &[Action::Close]),
+                ("Help", &[Action::Help]),
+            ],
+        ),
+        AppState::EventLog => hints(
+            model,
+            KeyContext::EventLog,
+            &[
+                ("Scroll", &[Action::Up, Action::Down]),
+                ("Close Popup", &[Action::Close]),
+                ("Help", &[Action::Help]),
+            ],
+        ),
+        AppState::Schedule => hints(
+            model,
+            KeyContext::Schedule,
+            &[
+                ("Sort", &[Action::Sort, Action::Reverse]),
+                ("Station", &[Action::FilterStation]),
+                ("Min El", &[Action::FilterElevation]),
+                ("Min Duration", &[Action::FilterDuration]),
+                ("Time of Day", &[Action::FilterTimeOfDay]),
+                ("Clear", &[Action::ClearFilters]),
+                ("Pass Detail", &[Action::Select]),
+                ("Close Popup", &[Action::Close]),
+                ("Help", &[Action::Help]),
+            ],
+        ),
+        AppState::Conjunctions => hints(
+            model,
+            KeyContext::Conjunctions,
+            &[
+                ("Run", &[Action::Run]),
+                ("Window", &[Action::Window]),
+                ("Threshold", &[Action::Threshold]),
+                ("Sort", &[Action::Sort, Action::Reverse]),
+                ("Close Popup", &[Action::Close]),
+                ("Help", &[Action::Help]),
+            ],
+        ),
+        AppState::Help => hints(
+            model,
+            KeyContext::Help,
+            &[
+                ("Scroll", &[Action::Up, Action::Down]),
+                ("Close Help", &[Action::Close]),
+            ],
+        ),
         _ => panic!("Should Never Occur"),
+    };
+    frame.render_widget(instruction.right_aligned(), area);
+}
+
+type Hint<'a> = (&'a str, &'a [Action]);
+
+/// Bottom bar instructions from the keymap, showing the first key of each action (falling back to its global binding)
+/// and leaving out unbound ones
+fn hints<'a>(model: &Model, context: KeyContext, hints: &[Hint<'a>]) -> Line<'a> {
+    let mut spans = vec![];
+    for (label, actions) in hints {
+        let keys: Vec<String> = actions
+            .iter()
+            .filter_map(|x| {
+                model
+                    .keymap
+                    .keys(context, *x)
+                    .first()
+                    .or(model.keymap.keys(KeyContext::Global, *x).first())
+            })
+            .map(|x| x.to_string())
+            .collect();
+        if keys.is_empty() {
+            continue;
+        }
+        spans.push(format!("{} ", label).into());
+        spans.push(format!("<{}> ", keys.join("/")).fg(model.theme.key).bold());
     }
+    Line::from(spans)
 }
 fn strf_seconds(seconds: i64) -> String {
     let working_seconds;
@@ -1,4 +1,4 @@
-use chrono::Utc;
+use chrono::{FixedOffset, TimeDelta};
 use ratatui::{
     Frame,
     layout::{Constraint, Layout, Rect},
@@ -8,6 +8,15 @@ use ratatui::{
 };
 
 use crate::Model;
+use crate::astro::{CelestialBody, look_angle, sub_point};
+use crate::pass_plan::range_rate;
+use crate::tle::Elements;
+
+/// Speed of light in km/s
+const SPEED_OF_LIGHT: f64 = 299_792.458;
+
+/// Seconds of local mean time per degree of longitude
+const SECONDS_PER_DEGREE: f64 = 240.0;
 
 pub fn view_sat_data(model: &Model, frame: &mut Frame, area: Option<Rect>) {
     let draw_area = area.unwrap_or(frame.area());
@@ -21,38 +30,107 @@ fn render_pass_block(model: &Model, frame: &mut Frame, draw_area: Rect) {
     let pass_stat_block = Block::bordered();
     let inner_area = pass_stat_block.inner(draw_area);
     frame.render_widget(pass_stat_block, draw_area);
+    if let Some(body) = model.target {
+        render_body_pointing(model, body, frame, inner_area);
+        return;
+    }
     let satellite = model.current_satellite.as_ref();
     let stations = &model.upcoming_passes;
     if satellite.is_some() && !stations.is_empty() {
         let upcoming_pass = &model.upcoming_passes[0];
-        let pointing = satellite.unwrap().satellite.get_look_angle(
-            &upcoming_pass.station,
-            satellite
-                .unwrap()
-                .satellite
-                .seconds_since_epoch(&Utc::now()),
-        );
+        let now = model.clock.now();
+        let tracked = &satellite.unwrap().satellite;
+        let offset = tracked.seconds_since_epoch(&now);
+        let pointing = tracked.get_look_angle(&upcoming_pass.station, offset);
+        let range_rate = range_rate(tracked, &upcoming_pass.station, offset);
+        let doppler = 1.0 - range_rate / SPEED_OF_LIGHT;
+        let frequency = model
+            .link
+            .settings
+            .satellite(tracked.get_norad_id())
+            .frequency;
+        let utc_offset = model
+            .station_config
+            .station_list
+            .iter()
+            .find(|x| x.station.name == upcoming_pass.station.name)
+            .and_then(|x| x.utc_offset)
+            .and_then(|x| FixedOffset::east_opt((x * 3600.0).round() as i32));
+        let station_time = match utc_offset {
+            Some(x) => now
+                .with_timezone(&x)
+                .format("%H:%M:%S (UTC%:z)")
+                .to_string(),
+            None => (now
+                + TimeDelta::seconds((upcoming_pass.station.long * SECONDS_PER_DEGREE) as i64))
+            .format("%H:%M:%S (LMT, no UTC offset set)")
+            .to_string(),
+        };
         let mut list_text = vec![
             Line::from(format!("Next Pass Station: {}", upcoming_pass.station.name)),
             Line::from(format!("Elevation: {:.2}deg", pointing.elevation)),
             Line::from(format!("Azimuth: {:.2}deg", pointing.azimuth)),
             Line::from(format!("Range: {:.2}km", pointing.range)),
-            Line::from(""), //WIll be local time at Ground station
+            Line::from(format!("Range Rate: {:.3}km/s", range_rate)),
+            Line::from(format!(
+                "Doppler: x{:.7}, {:+.2}kHz@{:.1}MHz",
+                doppler,
+                (doppler - 1.0) * frequency * 1000.0,
+                frequency
+            )),
+            Line::from(format!("Station Time: {}", station_time)),
             Line::from(""),
             Line::from("Upcoming Passes").centered().underlined(),
         ];
-        for i in model.upcoming_passes.iter().take(5) {
-            list_text.push(Line::from(format!(
+        let selected = model
+            .pass_detail
+            .selected
+            .min(model.upcoming_passes.len() - 1);
+        for (index, i) in model
+            .upcoming_passes
+            .iter()
+            .enumerate()
+            .skip(selected.saturating_sub(4))
+            .take(5)
+        {
+            let start = list_text.len() as u16;
+            let heading = Line::from(format!(
                 "{}: AOS {}(UTC)",
                 i.station.name,
                 i.pass.get_aos_datetime().format("%y-%m-%d %H:%M")
-            )));
+            ));
+            if index == selected {
+                list_text.push(heading.reversed());
+            } else {
+                list_text.push(heading);
+            }
             list_text.push(Line::from(format!(
                 "    Max. El: {:.1}deg, Duration: {}sec",
                 i.pass.get_max_elevation(),
                 i.pass.get_duration_sec()
             )));
-            list_text.push("".into())
+            if model.link.settings.sun_in_beam(i) {
+                list_text.push(
+                    Line::from(format!(
+                        "    Sun in beam: {}(UTC), {:.1}deg",
+                        i.sun.time.format("%H:%M:%S"),
+                        i.sun.separation
+                    ))
+                    .fg(model.theme.warning),
+                );
+            }
+            list_text.push("".into());
+            let rows = Rect::new(
+                inner_area.x,
+                inner_area.y + start,
+                inner_area.width,
+                list_text.len() as u16 - start,
+            );
+            model
+                .layout
+                .borrow_mut()
+                .passes
+                .push((rows.intersection(inner_area), index));
         }
 
         frame.render_widget(List::new(list_text), inner_area);
@@ -75,8 +153,35 @@ fn render_sat_block(model: &Model, frame: &mut Frame<'_>, draw_area: Rect) {
     let sat_stat_block = Block::bordered();
     let inner_area = sat_stat_block.inner(draw_area);
     frame.render_widget(sat_stat_block, draw_area);
-    if let Some(x) = model.current_satellite.as_ref() {
-        let current_time = Utc::now().timestamp();
+    if let Some(body) = model.target {
+        let now = model.clock.now();
+        let position = body.position(&now);
+        let (lat, long) = sub_point(&position, &now);
+        let text = vec![
+            Line::from(format!("Body: {}", body.name())),
+            Line::from(format!("Latitude: {:.2} deg", lat)),
+            Line::from(format!("Longitude: {:.2} deg", long)),
+            Line::from(format!("Distance: {:.0} km", position.distance)),
+            Line::from(""),
+            Line::from(format!(
+                "Right Ascension: {:.3} deg",
+                position.right_ascension
+            )),
+            Line::from(format!("Declination: {:.3} deg", position.declination)),
+        ];
+        frame.render_widget(List::new(text), inner_area)
+    } else if let Some(x) = model
+        .current_satellite
+        .as_ref()
+        .filter(|_| model.show_elements)
+    {
+        let text = match Elements::parse(x.satellite.get_tle()) {
+            Ok(elements) => elements_text(&elements),
+            Err(x) => vec![Line::from(format!("Unable to parse TLE: {}", x)).fg(model.theme.error)],
+        };
+        frame.render_widget(List::new(text), inner_area)
+    } else if let Some(x) = model.current_satellite.as_ref() {
+        let current_time = model.clock.now().timestamp();
         let base_offset = current_time - x.satellite.get_epoch().timestamp();
         let lla = x.satellite.get_sub_point(base_offset);
         let apo_peri = x.satellite.get_apogee_perigee();
@@ -115,3 +220,74 @@ fn render_sat_block(model: &Model, frame: &mut Frame<'_>, draw_area: Rect) {
         );
     }
 }
+
+/// The TLE's mean elements with the derived semi-major axis and orbit class
+fn elements_text(elements: &Elements) -> Vec<Line<'static>> {
+    vec![
+        Line::from("Elements (TLE)").centered().underlined(),
+        Line::from(format!(
+            "Epoch: {}",
+            elements.epoch.format("%y-%m-%d %H:%M:%S%.3f")
+        )),
+        Line::from(format!("Mean Motion: {:.8} rev/day", elements.mean_motion)),
+        Line::from(format!("  1st Deriv/2: {:+.8}", elements.mean_motion_dot)),
+        Line::from(format!("  2nd Deriv/6: {:+.5e}", elements.mean_motion_ddot)),
+        Line::from(format!("Eccentricity: {:.7}", elements.eccentricity)),
+        Line::from(format!("Inclination: {:.4} deg", elements.inclination)),
+        Line::from(format!("RAAN: {:.4} deg", elements.right_ascension)),
+        Line::from(format!(
+            "Arg. of Perigee: {:.4} deg",
+            elements.argument_of_perigee
+        )),
+        Line::from(format!("Mean Anomaly: {:.4} deg", elements.mean_anomaly)),
+        Line::from(format!("BSTAR: {:+.5e}", elements.bstar)),
+        Line::from(format!(
+            "Rev at Epoch: {}, Element Set: {}",
+            elements.revolution_number, elements.element_set
+        )),
+        Line::from(format!(
+            "Semi-major Axis: {:.2} km",
+            elements.semi_major_axis()
+        )),
+        Line::from(format!("Orbit Class: {}", elements.orbit_class().name())),
+    ]
+}
+
+/// Az/el of a celestial body from each active station, in place of the pass data
+fn render_body_pointing(model: &Model, body: CelestialBody, frame: &mut Frame, draw_area: Rect) {
+    let now = model.clock.now();
+    let position = body.position(&now);
+    let mut list_text = vec![
+        Line::from(format!("{} from active stations", body.name()))
+            .centered()
+            .underlined(),
+    ];
+    for i in model
+        .station_config
+        .station_list
+        .iter()
+        .filter(|x| x.active)
+    {
+        let pointing = look_angle(
+            &position,
+            &now,
+            i.station.lat,
+            i.station.long,
+            i.station.alt / 1000.0,
+        );
+        let elevation = Line::from(format!("    Elevation: {:.2}deg", pointing.elevation));
+        list_text.push(Line::from(i.station.name.clone()));
+        list_text.push(Line::from(format!(
+            "    Azimuth: {:.2}deg",
+            pointing.azimuth
+        )));
+        if pointing.elevation > 0.0 {
+            list_text.push(elevation.fg(model.theme.good));
+        } else {
+            list_text.push(elevation);
+        }
+        list_text.push(Line::from(format!("    Range: {:.0}km", pointing.range)));
+        list_text.push("".into());
+    }
+    frame.render_widget(List::new(list_text), draw_area);
+}
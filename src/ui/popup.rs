@@ -1,16 +1,28 @@
-use chrono::Utc;
+use chrono::{DateTime, Local};
 use ratatui::{
     Frame,
     layout::{Constraint, Flex, Layout, Position, Rect},
     style::{Color, Style, Stylize},
-    text::Line,
-    widgets::{Block, BorderType, Borders, Clear, List, Paragraph, Row, Table},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{
+        Axis, Block, BorderType, Borders, Chart, Clear, Dataset, GraphType, List, Paragraph, Row,
+        Table, Wrap,
+    },
 };
 use tracing::warn;
 
-use crate::structs::{AddSatSel, AppState, GSconfigState, Model};
+use crate::conjunction::ConjunctionSort;
+use crate::history::Outcome;
+use crate::keymap::{HelpRow, help_rows};
+use crate::link_budget::{LINK_CURVE_STEP, link_budget};
+use crate::pass_plan::look_at;
+use crate::sat_groups::{SatGroup, group_tles};
+use crate::sat_search::{MatchField, fuzzy_match};
+use crate::schedule::ScheduleSort;
+use crate::structs::{AddSatSel, AppState, GSconfigState, Model, SatEntry};
 
-use super::strf_seconds;
+use super::{strf_seconds, strf_seconds_small};
 
 pub fn view_popup_gs_config(model: &Model, frame: &mut Frame<'_>) {
     let area = popup_area(frame.area(), 35, 50);
@@ -27,6 +39,8 @@ pub fn view_popup_gs_config(model: &Model, frame: &mut Frame<'_>) {
         "Lat".to_string(),
         "Long".to_string(),
         "Alt".to_string(),
+        "Mask".to_string(),
+        "UTC".to_string(),
     ]);
     for (index, i) in model.station_config.station_list.iter().enumerate() {
         let mut carry: Vec<String> = vec![];
@@ -35,6 +49,14 @@ pub fn view_popup_gs_config(model: &Model, frame: &mut Frame<'_>) {
         } else {
             carry.push(" ".to_string())
         }
+        let mut fields = vec![
+            i.station.name.clone(),
+            format!("{}", i.station.lat),
+            format!("{}", i.station.long),
+            format!("{}", i.station.alt),
+            format!("{}", i.elevation_mask),
+            i.utc_offset.map(|x| format!("{:+}", x)).unwrap_or_default(),
+        ];
         if model.station_config.editing == GSconfigState::CellEdit
             && model
                 .station_config
@@ -43,50 +65,12 @@ pub fn view_popup_gs_config(model: &Model, frame: &mut Frame<'_>) {
                 .is_some_and(|x| index == x)
         {
             match model.station_config.table_state.selected_column().unwrap() {
-                1 => {
-                    carry.append(&mut vec![
-                        model.station_config.current_edit_buffer.clone(),
-                        format!("{}", i.station.lat),
-                        format!("{}", i.station.long),
-                        format!("{}", i.station.alt),
-                    ]);
-                }
-                2 => {
-                    carry.append(&mut vec![
-                        i.station.name.clone(),
-                        model.station_config.current_edit_buffer.clone(),
-                        format!("{}", i.station.long),
-                        format!("{}", i.station.alt),
-                    ]);
-                }
-                3 => {
-                    carry.append(&mut vec![
-                        i.station.name.clone(),
-                        format!("{}", i.station.lat),
-                        model.station_config.current_edit_buffer.clone(),
-                        format!("{}", i.station.alt),
-                    ]);
-                }
-                4 => {
-                    carry.append(&mut vec![
-                        i.station.name.clone(),
-                        format!("{}", i.station.lat),
-                        format!("{}", i.station.long),
-                        model.station_config.current_edit_buffer.clone(),
-                    ]);
-                }
+                x @ 1..=6 => fields[x - 1] = model.station_config.current_edit_buffer.clone(),
                 _ => warn!("GS config index out of range"),
             }
-            data.push(Row::new(carry))
-        } else {
-            carry.append(&mut vec![
-                i.station.name.clone(),
-                format!("{}", i.station.lat),
-                format!("{}", i.station.long),
-                format!("{}", i.station.alt),
-            ]);
-            data.push(Row::new(carry))
         }
+        carry.append(&mut fields);
+        data.push(Row::new(carry))
     }
     data.push(Row::new(vec![
         " ".to_string(),
@@ -94,6 +78,8 @@ pub fn view_popup_gs_config(model: &Model, frame: &mut Frame<'_>) {
         "0".to_string(),
         "0".to_string(),
         "0".to_string(),
+        "0".to_string(),
+        "".to_string(),
     ]));
     let widths = [
         Constraint::Length(7),
@@ -101,6 +87,8 @@ pub fn view_popup_gs_config(model: &Model, frame: &mut Frame<'_>) {
         Constraint::Fill(1),
         Constraint::Fill(1),
         Constraint::Fill(1),
+        Constraint::Fill(1),
+        Constraint::Fill(1),
     ];
     let table_widget: Table;
     match model.station_config.editing {
@@ -124,9 +112,16 @@ pub fn view_popup_gs_config(model: &Model, frame: &mut Frame<'_>) {
     };
     let mut current_table_state = model.station_config.table_state.clone();
     frame.render_stateful_widget(table_widget, gs_area, &mut current_table_state);
+    let rows = model.station_config.station_list.len() + 1;
+    // The rows start below the header
+    model.layout.borrow_mut().stations = (1..gs_area.height)
+        .map(|x| (x, current_table_state.offset() + x as usize - 1))
+        .filter(|x| x.1 < rows)
+        .map(|(x, y)| (Rect::new(gs_area.x, gs_area.y + x, gs_area.width, 1), y))
+        .collect();
     if model.station_config.current_msg.error {
         frame.render_widget(
-            Line::from(model.station_config.current_msg.text.as_ref()).red(),
+            Line::from(model.station_config.current_msg.text.as_ref()).fg(model.theme.error),
             message_area,
         );
     } else {
@@ -141,8 +136,12 @@ pub fn view_popup_sat_config(model: &Model, frame: &mut Frame) {
     let area = popup_area(frame.area(), 65, 50);
     frame.render_widget(Clear, area);
     let outer_block = Block::new().title_top(Line::from("Satellite Configuration").centered());
-    let left_side_block = Block::bordered().title("Current Satellites").on_dark_gray();
-    let right_side_block = Block::bordered().title("Satellite Details").on_dark_gray();
+    let left_side_block = Block::bordered()
+        .title("Current Satellites")
+        .bg(model.theme.panel);
+    let right_side_block = Block::bordered()
+        .title("Satellite Details")
+        .bg(model.theme.panel);
     let [list_area, detail_side] = Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)])
         .areas(outer_block.inner(area));
     frame.render_widget(outer_block, area);
@@ -154,7 +153,7 @@ pub fn view_popup_sat_config(model: &Model, frame: &mut Frame) {
     frame.render_widget(right_side_block, detail_side);
     if model.sat_config.current_message.error {
         frame.render_widget(
-            Line::from(model.sat_config.current_message.text.as_ref()).red(),
+            Line::from(model.sat_config.current_message.text.as_ref()).fg(model.theme.error),
             message_area,
         );
     } else {
@@ -174,7 +173,7 @@ fn render_sat_list_details(model: &Model, frame: &mut Frame<'_>, detail_area: Re
         .borders(Borders::all())
         .title_top("TLE")
         .border_type(BorderType::Rounded)
-        .border_style(Style::new().fg(Color::Cyan));
+        .border_style(Style::new().fg(model.theme.border));
     if model.current_state == AppState::SatAddition {
         if model.sat_config.add_sat.selected == AddSatSel::NoradID {
             let norad_id = model.sat_config.add_sat.text.clone();
@@ -203,11 +202,24 @@ fn render_sat_list_details(model: &Model, frame: &mut Frame<'_>, detail_area: Re
             ]);
             tle = Paragraph::new("").block(tle_block);
         } else {
-            details = Paragraph::new(vec![
-                Line::from("Satellite Name: XXXXX"),
-                Line::from("Satellite Norad ID:_____"),
-                Line::from("Current TLE age: 0 day(s), 0h 0m 0s"),
-            ]);
+            if model.sat_config.add_sat.errors.is_empty() {
+                details = Paragraph::new(vec![
+                    Line::from("Satellite Name: XXXXX"),
+                    Line::from("Satellite Norad ID:_____"),
+                    Line::from("Current TLE age: 0 day(s), 0h 0m 0s"),
+                ]);
+            } else {
+                details = Paragraph::new(
+                    model
+                        .sat_config
+                        .add_sat
+                        .errors
+                        .iter()
+                        .map(|x| Line::from(x.to_string()).fg(model.theme.error))
+                        .collect::<Vec<Line>>(),
+                )
+                .wrap(Wrap { trim: false });
+            }
             if model.sat_config.add_sat.editing {
                 let current_text = model.sat_config.add_sat.text.clone();
                 let mut y_offset: u16 = 1;
@@ -231,28 +243,73 @@ fn render_sat_list_details(model: &Model, frame: &mut Frame<'_>, detail_area: Re
             }
         }
     } else {
-        let mut current_sat = None;
-        if let Some(index) = model.sat_config.list_state.selected() {
-            if let Some(x) = model.sat_config.satellite_list.get(index) {
-                current_sat = Some(x)
-            };
-        };
-        match current_sat {
-            Some(sat) => {
-                let current_time = Utc::now().timestamp();
+        match model.sat_config.selected() {
+            Some(SatEntry::Body(body)) => {
+                let position = body.position(&model.clock.now());
+                details = Paragraph::new(format!(
+                    "Body: {}\nRight Ascension: {:.3}°\nDeclination: {:.3}°\nDistance: {:.0}km\nLow precision ephemeris, no passes are predicted\n",
+                    body.name(),
+                    position.right_ascension,
+                    position.declination,
+                    position.distance
+                ));
+                tle = Paragraph::new("").block(tle_block);
+            }
+            Some(SatEntry::Group(group)) => {
+                let members = model
+                    .sat_config
+                    .groups
+                    .tags
+                    .members(&group, &model.sat_config.satellite_list);
+                let oldest = members
+                    .iter()
+                    .map(|x| model.sat_config.satellite_list[*x].satellite.get_epoch())
+                    .min()
+                    .map(|x| strf_seconds(model.clock.now().timestamp() - x.timestamp()))
+                    .unwrap_or_default();
+                details = Paragraph::new(format!(
+                    "Group: {}\nSatellites: {}\nOldest TLE age: {}\n\nTrack all <Enter>\nRefresh all TLEs <f>\nExport TLEs <e>\n",
+                    group.name(),
+                    members.len(),
+                    oldest
+                ));
+                tle = Paragraph::new(group_tles(
+                    &members
+                        .iter()
+                        .map(|x| &model.sat_config.satellite_list[*x])
+                        .collect::<Vec<_>>(),
+                ))
+                .block(tle_block);
+            }
+            Some(SatEntry::Satellite(index)) => {
+                let sat = &model.sat_config.satellite_list[index];
+                let tags = &model.sat_config.groups.tags;
+                let norad_id = sat.satellite.get_norad_id();
+                let current_time = model.clock.now().timestamp();
                 let base_offset = current_time - sat.satellite.get_epoch().timestamp();
+                let groups = tags.groups_of(norad_id);
                 details = Paragraph::new(format!(
-                    "Satellite Name: {}\nSatellite Norad ID: {}\nSatellite Catelog ID: {}\nSatellite Country: {}\nSatellite Launch Date: {}\nCurrent TLE age: {}\n",
+                    "Satellite Name: {}\nSatellite Norad ID: {}\nSatellite Catelog ID: {}\nSatellite Country: {}\nSatellite Launch Date: {}\nCurrent TLE age: {}\nFavourite: {}\nGroups: {}\n",
                     sat.satellite.get_name(),
-                    sat.satellite.get_norad_id(),
+                    norad_id,
                     sat.metadata.object_id,
                     sat.metadata.owner,
                     sat.metadata.launch_date.format("%Y-%m-%d"),
-                    strf_seconds(base_offset)
+                    strf_seconds(base_offset),
+                    if tags.is_favourite(norad_id) {
+                        "Yes"
+                    } else {
+                        "No"
+                    },
+                    if groups.is_empty() {
+                        "None".to_string()
+                    } else {
+                        groups.join(", ")
+                    }
                 ));
                 tle = Paragraph::new(sat.satellite.get_tle()).block(tle_block);
             }
-            None => {
+            _ => {
                 details = Paragraph::new(
                     "Satellite Name: _____\nSatellite Norad ID: _____\nCurrent TLE age: 0 day(s), 0h 0m 0s\n",
                 );
@@ -270,30 +327,650 @@ fn render_sat_list(
     left_side_block: Block<'_>,
     list_area: Rect,
 ) {
-    let mut items: Vec<String> = model
-        .sat_config
-        .satellite_list
-        .clone()
+    let sat_config = &model.sat_config;
+    let search = &sat_config.search;
+    let tags = &sat_config.groups.tags;
+    let grouped = !tags.is_empty();
+    let marker = |current: bool| if current { "*" } else { "" };
+    let items: Vec<Line> = sat_config
+        .entries()
         .into_iter()
+        .map(|entry| match entry {
+            SatEntry::Satellite(x) => {
+                let satellite = &sat_config.satellite_list[x];
+                let current = model
+                    .current_satellite
+                    .as_ref()
+                    .is_some_and(|y| y.satellite == satellite.satellite);
+                let name = satellite.satellite.get_name();
+                let mut spans = vec![Span::from(marker(current))];
+                if grouped {
+                    spans.push(Span::from("  "));
+                }
+                if tags.is_favourite(satellite.satellite.get_norad_id()) {
+                    spans.push(Span::from("★ ").fg(model.theme.highlight));
+                }
+                match search.matches(satellite) {
+                    Some(x) if x.field == MatchField::Name => {
+                        spans.extend(highlighted(&name, &x.positions, model.theme.highlight))
+                    }
+                    Some(x) if !search.query.is_empty() => {
+                        spans.push(Span::from(name));
+                        spans.push(Span::from(" (").fg(model.theme.muted));
+                        spans.extend(highlighted(&x.text, &x.positions, model.theme.highlight));
+                        spans.push(Span::from(")").fg(model.theme.muted));
+                    }
+                    _ => spans.push(Span::from(name)),
+                }
+                Line::from(spans)
+            }
+            SatEntry::Body(x) => {
+                let positions = fuzzy_match(&search.query, x.name())
+                    .map(|y| y.1)
+                    .unwrap_or_default();
+                let mut spans = vec![Span::from(marker(model.target == Some(x)))];
+                spans.extend(highlighted(x.name(), &positions, model.theme.highlight));
+                Line::from(spans)
+            }
+            SatEntry::Group(x) => {
+                let count = tags.members(&x, &sat_config.satellite_list).len();
+                let tracked = model.tracked_group.as_ref() == Some(&x);
+                let name = match x {
+                    SatGroup::Favourites => format!("★ {} ({})", x.name(), count),
+                    _ => format!("{} ({})", x.name(), count),
+                };
+                Line::from(vec![Span::from(marker(tracked)), Span::from(name).bold()])
+            }
+            SatEntry::AddSatellite => Line::from("Add Satellite"),
+        })
+        .collect();
+    let mut block = left_side_block
+        .title_top(Line::from(format!("Sort: {} <o>", search.sort.name())).right_aligned());
+    if sat_config.groups.editing {
+        block = block.title_bottom(Line::from(vec![
+            Span::from(format!("Groups: {}", sat_config.groups.buffer)),
+            Span::from("_").slow_blink(),
+        ]));
+    } else if search.editing {
+        block = block.title_bottom(Line::from(vec![
+            Span::from(format!("/{}", search.query)),
+            Span::from("_").slow_blink(),
+        ]));
+    } else if !search.query.is_empty() {
+        block = block.title_bottom(format!("/{}", search.query));
+    } else {
+        block = block.title_bottom("Search </>");
+    }
+    let inner_area = block.inner(list_area);
+    let rows = items.len();
+    let list = List::new(items).block(block).highlight_symbol(">>");
+    let mut current_list_state = sat_config.list_state.clone();
+    frame.render_stateful_widget(list, list_area, &mut current_list_state);
+    model.layout.borrow_mut().satellites = (0..inner_area.height)
+        .map(|x| (x, current_list_state.offset() + x as usize))
+        .filter(|x| x.1 < rows)
+        .map(|(x, y)| {
+            (
+                Rect::new(inner_area.x, inner_area.y + x, inner_area.width, 1),
+                y,
+            )
+        })
+        .collect();
+}
+
+/// The text with the characters at `positions` highlighted as search matches
+fn highlighted(text: &str, positions: &[usize], color: Color) -> Vec<Span<'static>> {
+    text.chars()
+        .enumerate()
+        .map(|(index, x)| {
+            if positions.contains(&index) {
+                Span::from(x.to_string()).fg(color).bold()
+            } else {
+                Span::from(x.to_string())
+            }
+        })
+        .collect()
+}
+pub fn view_popup_pass_detail(model: &Model, frame: &mut Frame<'_>) {
+    let area = popup_area(frame.area(), 60, 70);
+    frame.render_widget(Clear, area);
+    let outer_block = Block::bordered().title_top(Line::from("Pass Detail").centered());
+    let [summary_area, table_area, message_area] = Layout::vertical([
+        Constraint::Length(6),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+    ])
+    .areas(outer_block.inner(area));
+    frame.render_widget(outer_block, area);
+    let (Some(satellite), Some(pass)) = (
+        model.current_satellite.as_ref(),
+        model.pass_detail.pass.as_ref(),
+    ) else {
+        return;
+    };
+    let pointing = |time: i64| look_at(&satellite.satellite, &pass.station, time).azimuth;
+    let sun_line = Line::from(format!(
+        "Closest to Sun: {}(UTC) {:.1}deg",
+        pass.sun.time.format("%y-%m-%d %H:%M:%S"),
+        pass.sun.separation
+    ));
+    let sun_line = if model.link.settings.sun_in_beam(pass) {
+        sun_line.fg(model.theme.warning).bold()
+    } else {
+        sun_line
+    };
+    let summary = vec![
+        Line::from(format!(
+            "{} over {}, step {}s",
+            satellite.satellite.get_name(),
+            pass.station.name,
+            model.pass_detail.step()
+        ))
+        .bold(),
+        Line::from(format!(
+            "AOS: {}(UTC) Az {:.1}deg",
+            pass.pass.get_aos_datetime().format("%y-%m-%d %H:%M:%S"),
+            pointing(pass.pass.get_aos())
+        )),
+        Line::from(format!(
+            "TME: {}(UTC) Az {:.1}deg, Max. El {:.1}deg",
+            pass.pass.get_tme_datetime().format("%y-%m-%d %H:%M:%S"),
+            pointing(pass.pass.get_tme_datetime().timestamp()),
+            pass.pass.get_max_elevation()
+        )),
+        Line::from(format!(
+            "LOS: {}(UTC) Az {:.1}deg",
+            pass.pass.get_los_datetime().format("%y-%m-%d %H:%M:%S"),
+            pointing(pass.pass.get_los())
+        )),
+        Line::from(format!(
+            "Duration: {}",
+            strf_seconds(pass.pass.get_duration_sec())
+        )),
+        sun_line,
+    ];
+    frame.render_widget(List::new(summary), summary_area);
+    let header = Row::new(vec![
+        "Time (UTC)",
+        "Az (deg)",
+        "El (deg)",
+        "Range (km)",
+        "Range Rate (km/s)",
+    ])
+    .underlined();
+    let data: Vec<Row> = model
+        .pass_detail
+        .plan
+        .iter()
+        .map(|x| {
+            Row::new(vec![
+                x.time.format("%H:%M:%S").to_string(),
+                format!("{:.2}", x.azimuth),
+                format!("{:.2}", x.elevation),
+                format!("{:.1}", x.range),
+                format!("{:.3}", x.range_rate),
+            ])
+        })
+        .collect();
+    let widths = [
+        Constraint::Length(10),
+        Constraint::Fill(1),
+        Constraint::Fill(1),
+        Constraint::Fill(1),
+        Constraint::Fill(1),
+    ];
+    let table_widget = Table::new(data, widths)
+        .header(header)
+        .row_highlight_style(Style::new().reversed());
+    let mut current_table_state = model.pass_detail.table_state.clone();
+    frame.render_stateful_widget(table_widget, table_area, &mut current_table_state);
+    if model.pass_detail.current_message.error {
+        frame.render_widget(
+            Line::from(model.pass_detail.current_message.text.as_ref()).fg(model.theme.error),
+            message_area,
+        );
+    } else {
+        frame.render_widget(
+            Line::from(model.pass_detail.current_message.text.as_ref()),
+            message_area,
+        );
+    }
+}
+
+pub fn view_popup_conjunctions(model: &Model, frame: &mut Frame<'_>) {
+    let area = popup_area(frame.area(), 60, 70);
+    frame.render_widget(Clear, area);
+    let outer_block = Block::bordered().title_top(Line::from("Conjunction Screening").centered());
+    let [settings_area, table_area, message_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+    ])
+    .areas(outer_block.inner(area));
+    frame.render_widget(outer_block, area);
+    let conjunctions = &model.conjunctions;
+    frame.render_widget(
+        Line::from(format!(
+            "Window: {}h from {}(UTC), Threshold: {}km, Satellites: {}",
+            conjunctions.window() / 3600,
+            model.clock.now().format("%y-%m-%d %H:%M"),
+            conjunctions.threshold(),
+            model.sat_config.satellite_list.len()
+        )),
+        settings_area,
+    );
+    let columns = [
+        (Some(ConjunctionSort::Time), "TCA (UTC)"),
+        (Some(ConjunctionSort::Primary), "Primary"),
+        (None, "Secondary"),
+        (Some(ConjunctionSort::MissDistance), "Miss (km)"),
+        (Some(ConjunctionSort::RelativeVelocity), "Rel. Vel (km/s)"),
+    ];
+    let header = Row::new(columns.map(|(sort, name)| {
+        if sort != Some(conjunctions.sort) {
+            name.to_string()
+        } else if conjunctions.descending {
+            format!("{name} ▼")
+        } else {
+            format!("{name} ▲")
+        }
+    }))
+    .underlined();
+    let data: Vec<Row> = conjunctions
+        .sorted()
+        .iter()
+        .map(|x| {
+            Row::new(vec![
+                x.time.format("%y-%m-%d %H:%M:%S").to_string(),
+                x.primary.clone(),
+                x.secondary.clone(),
+                format!("{:.3}", x.miss_distance),
+                format!("{:.3}", x.relative_velocity),
+            ])
+        })
+        .collect();
+    let widths = [
+        Constraint::Length(19),
+        Constraint::Fill(1),
+        Constraint::Fill(1),
+        Constraint::Length(12),
+        Constraint::Length(18),
+    ];
+    let table_widget = Table::new(data, widths)
+        .header(header)
+        .highlight_symbol(">>")
+        .row_highlight_style(Style::new().reversed());
+    let mut current_table_state = conjunctions.table_state.clone();
+    frame.render_stateful_widget(table_widget, table_area, &mut current_table_state);
+    frame.render_widget(
+        Line::from(conjunctions.current_message.text.as_str()),
+        message_area,
+    );
+}
+
+pub fn view_popup_schedule(model: &Model, frame: &mut Frame<'_>) {
+    let area = popup_area(frame.area(), 60, 70);
+    frame.render_widget(Clear, area);
+    let outer_block = Block::bordered().title_top(Line::from("Pass Schedule").centered());
+    let [filter_area, table_area, message_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+    ])
+    .areas(outer_block.inner(area));
+    frame.render_widget(outer_block, area);
+    let schedule = &model.schedule;
+    let (start, end) = schedule.filter.time_of_day();
+    frame.render_widget(
+        Line::from(format!(
+            "Station: {}, Max. El >= {}deg, Duration >= {}s, AOS {:02}:00-{:02}:00 (local)",
+            schedule.filter.station.as_deref().unwrap_or("All"),
+            schedule.filter.min_elevation(),
+            schedule.filter.min_duration(),
+            start,
+            end
+        )),
+        filter_area,
+    );
+    let columns = [
+        (ScheduleSort::Station, "Station"),
+        (ScheduleSort::Aos, "AOS (UTC)"),
+        (ScheduleSort::Los, "LOS (UTC)"),
+        (ScheduleSort::Duration, "Duration"),
+        (ScheduleSort::MaxElevation, "Max. El"),
+    ];
+    let mut header: Vec<String> = columns
+        .iter()
+        .map(|(sort, name)| {
+            if *sort != schedule.sort {
+                name.to_string()
+            } else if schedule.descending {
+                format!("{name} ▼")
+            } else {
+                format!("{name} ▲")
+            }
+        })
+        .collect();
+    header.push("Sun in Beam".to_string());
+    let header = Row::new(header).underlined();
+    let passes = schedule.passes(&model.upcoming_passes);
+    let data: Vec<Row> = passes
+        .iter()
+        .map(|x| {
+            Row::new(vec![
+                x.station.name.clone(),
+                x.pass
+                    .get_aos_datetime()
+                    .format("%y-%m-%d %H:%M:%S")
+                    .to_string(),
+                x.pass
+                    .get_los_datetime()
+                    .format("%y-%m-%d %H:%M:%S")
+                    .to_string(),
+                strf_seconds_small(x.pass.get_duration_sec())
+                    .trim_start_matches('+')
+                    .to_string(),
+                format!("{:.1}deg", x.pass.get_max_elevation()),
+                if model.link.settings.sun_in_beam(x) {
+                    x.sun.time.format("%H:%M:%S").to_string()
+                } else {
+                    String::new()
+                },
+            ])
+        })
+        .collect();
+    let widths = [
+        Constraint::Fill(2),
+        Constraint::Length(19),
+        Constraint::Length(19),
+        Constraint::Length(10),
+        Constraint::Length(10),
+        Constraint::Length(12),
+    ];
+    let table_widget = Table::new(data, widths)
+        .header(header)
+        .highlight_symbol(">>")
+        .row_highlight_style(Style::new().reversed());
+    let mut current_table_state = schedule.table_state.clone();
+    frame.render_stateful_widget(table_widget, table_area, &mut current_table_state);
+    frame.render_widget(
+        Line::from(format!(
+            "Showing {} of {} passes",
+            passes.len(),
+            model.upcoming_passes.len()
+        )),
+        message_area,
+    );
+}
+
+pub fn view_popup_event_log(model: &Model, frame: &mut Frame<'_>) {
+    let area = popup_area(frame.area(), 70, 60);
+    frame.render_widget(Clear, area);
+    let outer_block = Block::bordered().title_top(Line::from("Event Log").centered());
+    let items: Vec<Line> = model
+        .hooks
+        .log
+        .iter()
+        .rev()
         .map(|x| {
-            if model
-                .current_satellite
-                .as_ref()
-                .is_some_and(|y| y.satellite == x.satellite)
-            {
-                format!("*{}", x.satellite.get_name())
+            let line = Line::from(format!(
+                "{} {}",
+                x.time.with_timezone(&Local).format("%H:%M:%S"),
+                x.text
+            ));
+            if x.error {
+                line.fg(model.theme.error)
             } else {
-                x.satellite.get_name()
+                line
             }
         })
         .collect();
-    items.push("Add Satellite".to_string());
     let list = List::new(items)
-        .block(left_side_block)
-        .highlight_symbol(">>");
-    let mut current_list_state = model.sat_config.list_state.clone();
-    frame.render_stateful_widget(list, list_area, &mut current_list_state);
+        .block(outer_block)
+        .highlight_style(Style::new().reversed());
+    let mut current_list_state = model.event_log_state.clone();
+    frame.render_stateful_widget(list, area, &mut current_list_state);
+}
+
+pub fn view_popup_history(model: &Model, frame: &mut Frame<'_>) {
+    let area = popup_area(frame.area(), 70, 60);
+    frame.render_widget(Clear, area);
+    let outer_block = Block::bordered().title_top(Line::from("Pass History").centered());
+    let [table_area, message_area] = Layout::vertical([Constraint::Fill(1), Constraint::Length(1)])
+        .areas(outer_block.inner(area));
+    frame.render_widget(outer_block, area);
+    let history = &model.history;
+    let header = Row::new(vec![
+        "Satellite",
+        "Station",
+        "AOS (UTC)",
+        "LOS (UTC)",
+        "Max. El",
+        "Outcome",
+        "Notes",
+    ])
+    .underlined();
+    let data: Vec<Row> = history
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(index, x)| {
+            let notes = if history.editing && history.table_state.selected() == Some(index) {
+                format!("{}_", history.edit_buffer)
+            } else {
+                x.notes.clone()
+            };
+            let outcome = match x.outcome {
+                Outcome::Unknown => Line::from("-"),
+                Outcome::Success => Line::from("Success").fg(model.theme.good),
+                Outcome::Partial => Line::from("Partial").fg(model.theme.warning),
+                Outcome::Missed => Line::from("Missed").fg(model.theme.error),
+            };
+            Row::new(vec![
+                Line::from(x.satellite.clone()),
+                Line::from(x.station.clone()),
+                Line::from(x.aos.format("%y-%m-%d %H:%M").to_string()),
+                Line::from(x.los.format("%y-%m-%d %H:%M").to_string()),
+                Line::from(format!("{:.1}deg", x.max_elevation)),
+                outcome,
+                Line::from(notes),
+            ])
+        })
+        .collect();
+    let widths = [
+        Constraint::Fill(1),
+        Constraint::Fill(1),
+        Constraint::Length(15),
+        Constraint::Length(15),
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Fill(2),
+    ];
+    let highlight = if history.editing {
+        Style::new().underlined()
+    } else {
+        Style::new().reversed()
+    };
+    let table_widget = Table::new(data, widths)
+        .header(header)
+        .highlight_symbol(">>")
+        .row_highlight_style(highlight);
+    let mut current_table_state = history.table_state.clone();
+    frame.render_stateful_widget(table_widget, table_area, &mut current_table_state);
+    if history.current_message.error {
+        frame.render_widget(
+            Line::from(history.current_message.text.as_ref()).fg(model.theme.error),
+            message_area,
+        );
+    } else {
+        frame.render_widget(
+            Line::from(history.current_message.text.as_ref()),
+            message_area,
+        );
+    }
+}
+
+pub fn view_popup_link_budget(model: &Model, frame: &mut Frame<'_>) {
+    let area = popup_area(frame.area(), 60, 70);
+    frame.render_widget(Clear, area);
+    let outer_block = Block::bordered().title_top(Line::from("Link Budget").centered());
+    let [text_area, chart_area, interval_area] = Layout::vertical([
+        Constraint::Length(7),
+        Constraint::Fill(1),
+        Constraint::Length(2),
+    ])
+    .areas(outer_block.inner(area));
+    frame.render_widget(outer_block, area);
+    let (Some(satellite), Some(pass)) =
+        (model.current_satellite.as_ref(), model.link.pass.as_ref())
+    else {
+        return;
+    };
+    let settings = &model.link.settings;
+    let sat_radio = settings.satellite(satellite.satellite.get_norad_id());
+    let station_radio = settings.station(&pass.station.name);
+    let threshold = settings.margin_threshold;
+    let live = look_at(
+        &satellite.satellite,
+        &pass.station,
+        model.clock.now().timestamp(),
+    );
+    let live_budget = link_budget(&sat_radio, &station_radio, live.range);
+    let live_margin = Line::from(format!(
+        "Eb/N0: {:.1}dB at {:.0}bit/s, Margin: {:.1}dB{}",
+        live_budget.ebn0,
+        sat_radio.data_rate,
+        live_budget.margin,
+        if live.elevation < 0.0 {
+            " (below horizon)"
+        } else {
+            ""
+        }
+    ));
+    let text = vec![
+        Line::from(format!(
+            "{} to {}, AOS {}(UTC)",
+            satellite.satellite.get_name(),
+            pass.station.name,
+            pass.pass.get_aos_datetime().format("%y-%m-%d %H:%M")
+        ))
+        .bold(),
+        Line::from(format!(
+            "EIRP: {:.1}dBW, Frequency: {:.3}MHz, Required Eb/N0: {:.1}dB",
+            sat_radio.eirp, sat_radio.frequency, sat_radio.required_ebn0
+        )),
+        Line::from(format!(
+            "Gain: {:.1}dBi, Tsys: {:.0}K, G/T: {:.1}dB/K, Losses: {:.1}dB",
+            station_radio.gain,
+            station_radio.noise_temperature,
+            station_radio.gain - 10.0 * station_radio.noise_temperature.log10(),
+            station_radio.losses
+        )),
+        Line::from(""),
+        Line::from(format!(
+            "Now: Range {:.0}km, FSPL {:.1}dB, Pr {:.1}dBW, C/N0 {:.1}dBHz",
+            live.range, live_budget.path_loss, live_budget.received_power, live_budget.cn0
+        )),
+        if live_budget.margin < threshold {
+            live_margin.fg(model.theme.error)
+        } else {
+            live_margin.fg(model.theme.good)
+        },
+    ];
+    frame.render_widget(List::new(text), text_area);
+
+    let curve = &model.link.curve;
+    let margin_data: Vec<(f64, f64)> = curve.iter().map(|x| (x.0, x.1)).collect();
+    let low_data: Vec<(f64, f64)> = curve
+        .iter()
+        .filter(|x| x.1 < threshold)
+        .map(|x| (x.0, x.1))
+        .collect();
+    let duration = curve.last().map(|x| x.0).unwrap_or(1.0).max(1.0 / 60.0);
+    let threshold_data = [(0.0, threshold), (duration, threshold)];
+    let (min, max) = curve.iter().fold((threshold, threshold), |acc, x| {
+        (acc.0.min(x.1), acc.1.max(x.1))
+    });
+    let y_bounds = [(min - 3.0).floor(), (max + 3.0).ceil()];
+    let datasets = vec![
+        Dataset::default()
+            .name("Margin (dB)")
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .data(&margin_data)
+            .fg(model.theme.track_future),
+        Dataset::default()
+            .name("Threshold")
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .data(&threshold_data)
+            .fg(model.theme.muted),
+        Dataset::default()
+            .marker(Marker::Dot)
+            .graph_type(GraphType::Scatter)
+            .data(&low_data)
+            .fg(model.theme.error),
+    ];
+    let x_axis = Axis::default()
+        .title("Minutes from AOS")
+        .bounds([0.0, duration])
+        .labels([
+            "0".to_string(),
+            format!("{:.1}", duration / 2.0),
+            format!("{:.1}", duration),
+        ]);
+    let y_axis = Axis::default()
+        .title("Margin (dB)")
+        .bounds(y_bounds)
+        .labels([
+            format!("{:.0}", y_bounds[0]),
+            format!("{:.0}", (y_bounds[0] + y_bounds[1]) / 2.0),
+            format!("{:.0}", y_bounds[1]),
+        ]);
+    frame.render_widget(
+        Chart::new(datasets).x_axis(x_axis).y_axis(y_axis),
+        chart_area,
+    );
+
+    let mut intervals: Vec<(i64, i64)> = vec![];
+    for (_, margin, time) in curve {
+        if *margin < threshold {
+            match intervals.last_mut() {
+                Some(last) if time - last.1 <= LINK_CURVE_STEP => last.1 = *time,
+                _ => intervals.push((*time, *time)),
+            }
+        }
+    }
+    let format_time = |x: i64| {
+        DateTime::from_timestamp(x, 0)
+            .unwrap_or_default()
+            .format("%H:%M:%S")
+            .to_string()
+    };
+    let interval_text = if intervals.is_empty() {
+        Line::from(format!(
+            "Margin stays above {:.1}dB for the whole pass",
+            threshold
+        ))
+        .fg(model.theme.good)
+    } else {
+        Line::from(format!(
+            "Margin below {:.1}dB: {}",
+            threshold,
+            intervals
+                .iter()
+                .map(|x| format!("{}-{}", format_time(x.0), format_time(x.1)))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ))
+        .fg(model.theme.error)
+    };
+    frame.render_widget(
+        Paragraph::new(interval_text).wrap(Wrap { trim: true }),
+        interval_area,
+    );
 }
+
 fn popup_area(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
     let vertical = Layout::vertical([Constraint::Percentage(percent_y)]).flex(Flex::Center);
     let horizontal = Layout::horizontal([Constraint::Percentage(percent_x)]).flex(Flex::Center);
@@ -301,3 +978,33 @@ fn popup_area(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
     let [area] = horizontal.areas(area);
     area
 }
+
+pub fn view_popup_help(model: &Model, frame: &mut Frame<'_>) {
+    let area = popup_area(frame.area(), 50, 70);
+    frame.render_widget(Clear, area);
+    let outer_block = Block::bordered().title_top(Line::from("Key Bindings").centered());
+    let mut lines: Vec<Line> = help_rows(&model.keymap, model.help.context)
+        .into_iter()
+        .map(|x| match x {
+            HelpRow::Heading(x) => Line::from(x).bold().underlined(),
+            HelpRow::Binding { keys, description } => Line::from(vec![
+                Span::from(format!("{:>16}  ", keys))
+                    .fg(model.theme.key)
+                    .bold(),
+                Span::from(description),
+            ]),
+            HelpRow::Blank => Line::from(""),
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(
+        Line::from("While typing in a field Enter finishes, Esc leaves it and Backspace deletes")
+            .fg(model.theme.muted),
+    );
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(outer_block)
+            .scroll((model.help.scroll as u16, 0)),
+        area,
+    );
+}
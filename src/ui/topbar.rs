@@ -2,6 +2,7 @@ use chrono::{Datelike, Local, TimeDelta, Utc};
 use ratatui::{
     Frame,
     layout::{Constraint, Layout, Rect},
+    style::Stylize,
     text::Line,
     widgets::{Block, Gauge, List, Row, Table},
 };
@@ -12,6 +13,7 @@ use super::strf_seconds_small;
 
 pub fn view_top_bar(model: &Model, frame: &mut Frame, area: Option<Rect>) {
     let draw_area = area.unwrap_or(frame.area());
+    let now = model.clock.now();
     let [realtime, center, met_time] = Layout::horizontal([
         Constraint::Length(55),
         Constraint::Fill(1),
@@ -21,10 +23,10 @@ pub fn view_top_bar(model: &Model, frame: &mut Frame, area: Option<Rect>) {
 
     let rt_frame = Block::bordered();
     let mut rt_text = vec![
-        Line::from(format!("  UTC: {}", Utc::now().format("%Y-%m-%d %H:%M:%S"))).centered(),
+        Line::from(format!("  UTC: {}", now.format("%Y-%m-%d %H:%M:%S"))).centered(),
         Line::from(format!(
             "LOCAL: {}",
-            Local::now().format("%Y-%m-%d %H:%M:%S")
+            now.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S")
         ))
         .centered(),
     ];
@@ -32,7 +34,7 @@ pub fn view_top_bar(model: &Model, frame: &mut Frame, area: Option<Rect>) {
         rt_text.push(
             Line::from(format!(
                 "  MET: {} days",
-                (Utc::now().num_days_from_ce() - x.metadata.launch_date.num_days_from_ce())
+                (now.num_days_from_ce() - x.metadata.launch_date.num_days_from_ce())
             ))
             .centered(),
         );
@@ -42,7 +44,22 @@ pub fn view_top_bar(model: &Model, frame: &mut Frame, area: Option<Rect>) {
     frame.render_widget(List::new(rt_text), rt_inner);
 
     let track_frame = Block::bordered();
-    let center_text = vec![Line::from(""), Line::from("Trackellite").centered()];
+    let mut center_text = vec![Line::from(""), Line::from("Trackellite").centered()];
+    if !model.clock.is_real_time() {
+        center_text.push(
+            Line::from(format!(
+                "SIMULATED TIME ({}) T{}",
+                model.clock.describe(),
+                strf_seconds_small(now.signed_duration_since(Utc::now()).num_seconds())
+            ))
+            .centered()
+            .fg(model.theme.error)
+            .bold(),
+        );
+    }
+    if !model.alerts.settings.enabled {
+        center_text.push(Line::from("ALERTS OFF").centered().fg(model.theme.muted));
+    }
 
     let center_inner = track_frame.inner(center);
     frame.render_widget(track_frame, center);
@@ -61,22 +78,28 @@ pub fn view_top_bar(model: &Model, frame: &mut Frame, area: Option<Rect>) {
     } else {
         let pass = model.upcoming_passes[0].clone();
         let widths = vec![Constraint::Fill(1), Constraint::Fill(1)];
-        let aos_time_till = Utc::now().signed_duration_since(pass.pass.get_aos_datetime());
-        let los_time_till = Utc::now().signed_duration_since(pass.pass.get_los_datetime());
+        let aos_time_till = now.signed_duration_since(pass.pass.get_aos_datetime());
+        let los_time_till = now.signed_duration_since(pass.pass.get_los_datetime());
+        let mut countdown = Row::new(vec![
+            "Upcoming Pass".into(),
+            format!(
+                "Time to AOS: T{}",
+                strf_seconds_small(aos_time_till.num_seconds())
+            ),
+        ]);
+        if model.alerts.settings.enabled
+            && aos_time_till < TimeDelta::zero()
+            && aos_time_till >= -TimeDelta::minutes(model.alerts.settings.lead_minutes)
+        {
+            countdown = countdown.fg(model.theme.warning).bold();
+        }
         let mut pass_text = vec![
-            Row::new(vec![
-                "Upcoming Pass".into(),
-                format!(
-                    "Time to AOS: T{}",
-                    strf_seconds_small(aos_time_till.num_seconds())
-                ),
-            ]),
+            countdown,
             Row::new(vec![
                 format!(
                     "Time to TME: T{}",
                     strf_seconds_small(
-                        Utc::now()
-                            .signed_duration_since(pass.pass.get_tme_datetime())
+                        now.signed_duration_since(pass.pass.get_tme_datetime())
                             .num_seconds()
                     )
                 ),
@@ -86,12 +109,12 @@ pub fn view_top_bar(model: &Model, frame: &mut Frame, area: Option<Rect>) {
                 ),
             ]),
         ];
-        if Utc::now().signed_duration_since(pass.pass.get_aos_datetime()) > TimeDelta::zero() {
+        if now.signed_duration_since(pass.pass.get_aos_datetime()) > TimeDelta::zero() {
             let [table_space, bar_space] =
                 Layout::vertical([Constraint::Length(2), Constraint::Length(1)]).areas(met_inner);
             let pass_duration =
                 (pass.pass.get_los_datetime() - pass.pass.get_aos_datetime()).num_seconds();
-            let current_progress_seconds = Utc::now()
+            let current_progress_seconds = now
                 .signed_duration_since(pass.pass.get_aos_datetime())
                 .num_seconds();
             let ratio;
@@ -1,4 +1,4 @@
-use chrono::Utc;
+use chrono::DateTime;
 use ratatui::{
     Frame,
     layout::Rect,
@@ -6,26 +6,127 @@ use ratatui::{
     symbols::Marker,
     widgets::{
         Axis, Block, Chart, Dataset, GraphType,
-        canvas::{Canvas, Map, MapResolution},
+        canvas::{Canvas, Context, Line, Points},
     },
 };
 use tracing::debug;
 
-use crate::structs::Model;
+use crate::astro::{CELESTIAL_BODIES, CelestialBody, sub_point, sun_position};
+use crate::geometry::{EARTH_RADIUS, distance_bearing, footprint_radius, small_circle};
+use crate::projection::MapProjection;
+use crate::structs::{MapFollow, Model, Projection};
+
+/// Number of points used to draw circles on the map
+const CIRCLE_SAMPLES: usize = 180;
+
+/// Sun depression angles (deg) of the civil, nautical and astronomical twilight boundaries
+const TWILIGHT_DEPRESSIONS: [f64; 3] = [6.0, 12.0, 18.0];
 
 pub fn view_ground_track(model: &Model, frame: &mut Frame, area: Option<Rect>) {
     let draw_area = area.unwrap_or(frame.area());
-    let gt_frame = Block::bordered();
+    let mut gt_frame = Block::bordered();
+    if let Some(x) = map_title(model) {
+        gt_frame = gt_frame.title_top(x);
+    }
+    if let Some(x) = cursor_title(model) {
+        gt_frame = gt_frame.title_bottom(x);
+    }
     let internal_area = gt_frame.inner(draw_area);
+    model.layout.borrow_mut().map = internal_area;
     frame.render_widget(gt_frame, draw_area);
-    render_background_map(frame, internal_area);
+    let projection = MapProjection::new(model, internal_area);
+    render_background_map(model, &projection, frame, internal_area);
+    render_daylight(model, &projection, frame, internal_area);
     if model.current_satellite.is_some() {
-        render_tracks(model, frame, internal_area);
-    } else {
-        render_no_sat_text(frame, internal_area);
+        render_tracks(model, &projection, frame, internal_area);
+    } else if model.target.is_none() {
+        render_no_sat_text(model, frame, internal_area);
+    }
+    render_group(model, &projection, frame, internal_area);
+    render_bodies(model, &projection, frame, internal_area);
+    render_cursor(model, &projection, frame, internal_area);
+}
+
+/// The clicked position and the nearest ground station to it
+fn cursor_title(model: &Model) -> Option<String> {
+    let (lat, long) = model.map.cursor?;
+    let mut title = format!(
+        "{:.3}°{} {:.3}°{}",
+        lat.abs(),
+        if lat < 0.0 { "S" } else { "N" },
+        long.abs(),
+        if long < 0.0 { "W" } else { "E" }
+    );
+    let nearest = model
+        .station_config
+        .station_list
+        .iter()
+        .map(|x| {
+            let (distance, _) = distance_bearing(lat, long, x.station.lat, x.station.long);
+            (&x.station.name, distance.to_radians() * EARTH_RADIUS)
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+    if let Some((name, distance)) = nearest {
+        title.push_str(&format!(", nearest station {} ({:.0}km)", name, distance));
     }
+    Some(title)
 }
-fn render_no_sat_text(frame: &mut Frame<'_>, draw_area: Rect) {
+
+fn render_cursor(
+    model: &Model,
+    projection: &MapProjection,
+    frame: &mut Frame<'_>,
+    draw_area: Rect,
+) {
+    let Some((lat, long)) = model.map.cursor else {
+        return;
+    };
+    let (x, y) = projection.project(lat, long);
+    frame.render_widget(
+        map_canvas(projection, |ctx| {
+            ctx.print(x, y, "+".fg(model.theme.cursor).bold());
+        }),
+        draw_area,
+    );
+}
+
+/// Describes the map view when it differs from the default whole world view
+fn map_title(model: &Model) -> Option<String> {
+    let settings = &model.map;
+    if settings.projection == Projection::Equirectangular
+        && settings.follow == MapFollow::Free
+        && settings.zoom == 1.0
+        && settings.pan == (0.0, 0.0)
+        && settings.central_meridian == 0.0
+    {
+        return None;
+    }
+    let projection = match settings.projection {
+        Projection::Equirectangular => {
+            format!("Equirectangular {:.0}E", settings.central_meridian)
+        }
+        Projection::AzimuthalEquidistant => "Azimuthal Equidistant".to_string(),
+    };
+    let follow = match settings.follow {
+        MapFollow::Free => "",
+        MapFollow::Station => ", centred on station",
+        MapFollow::Satellite => ", following satellite",
+    };
+    Some(format!(" {} x{}{} ", projection, settings.zoom, follow))
+}
+
+fn map_canvas<'a, F>(projection: &MapProjection, painter: F) -> Canvas<'a, F>
+where
+    F: Fn(&mut Context),
+{
+    Canvas::default()
+        .marker(Marker::Braille)
+        .paint(painter)
+        .x_bounds(projection.x_bounds())
+        .y_bounds(projection.y_bounds())
+}
+
+fn render_no_sat_text(model: &Model, frame: &mut Frame<'_>, draw_area: Rect) {
     frame.render_widget(
         Canvas::default()
             .paint(|ctx| {
@@ -33,7 +134,7 @@ fn render_no_sat_text(frame: &mut Frame<'_>, draw_area: Rect) {
                     -0.15,
                     0.0,
                     "Add a satellite to begin tracking"
-                        .yellow()
+                        .fg(model.theme.highlight)
                         .into_centered_line(),
                 )
             })
@@ -43,89 +144,40 @@ fn render_no_sat_text(frame: &mut Frame<'_>, draw_area: Rect) {
     );
 }
 
-fn render_tracks(model: &Model, frame: &mut Frame<'_>, draw_area: Rect) {
-    let current_time = Utc::now().timestamp();
+fn render_tracks(
+    model: &Model,
+    projection: &MapProjection,
+    frame: &mut Frame<'_>,
+    draw_area: Rect,
+) {
+    let current_time = model.clock.now().timestamp();
     let working_satellites = model.current_satellite.as_ref().unwrap();
     let base_offset = current_time - working_satellites.satellite.get_epoch().timestamp();
     let current_pos = working_satellites.satellite.get_sub_point(base_offset);
-    let points: Vec<(f64, f64)> = ((base_offset - 300)..(base_offset + model.sub_point_range))
-        .map(|x| {
-            let sub_point = working_satellites.satellite.get_sub_point(x);
-            (sub_point.long, sub_point.lat)
-        })
-        .collect();
-    let mut prev: Option<f64> = None;
+    let track_cache = &model.ground_track;
     let mut paths_list: Vec<Dataset> = vec![];
-    let mut current_start: usize = 0;
-    let mut current_end: usize = 0;
-    let direction;
-    let points_less = points
-        .iter()
-        .enumerate()
-        .filter(|(a, b)| {
-            let next_point = points.get(*a + 1);
-            if let Some(pt) = next_point {
-                b.0 < pt.0
-            } else {
-                false
-            }
-        })
-        .count();
-    let points_more = points
-        .iter()
-        .enumerate()
-        .filter(|(a, b)| {
-            let next_point = points.get(*a + 1);
-            if let Some(pt) = next_point {
-                b.0 > pt.0
-            } else {
-                false
-            }
-        })
-        .count();
-    if points_less > points_more {
-        direction = true;
-        debug!("Direction: {direction}");
-    } else {
-        direction = false;
-        debug!("Direction: {direction}: points_less: {points_less}, points_more: {points_more}");
-    }
-    for i in &points {
-        if direction {
-            if prev.is_some() && prev.unwrap() > i.0 {
-                let dataset = Dataset::default()
-                    .name(working_satellites.satellite.get_name())
-                    .marker(Marker::Braille)
-                    .graph_type(GraphType::Line)
-                    .cyan()
-                    .data(&points[current_start..current_end]);
-                paths_list.push(dataset);
-                current_start = current_end + 1;
+    for segment in track_cache.segments() {
+        let split = segment.times.partition_point(|x| *x <= current_time as f64);
+        let past = &segment.points[..(split + 1).min(segment.points.len())];
+        let future = &segment.points[split.saturating_sub(1)..];
+        for (data, color) in [
+            (past, model.theme.track_past),
+            (future, model.theme.track_future),
+        ] {
+            if data.len() > 1 {
+                paths_list.push(
+                    Dataset::default()
+                        .name(working_satellites.satellite.get_name())
+                        .marker(Marker::Braille)
+                        .graph_type(GraphType::Line)
+                        .data(data)
+                        .fg(color),
+                );
             }
-        } else if prev.is_some() && prev.unwrap() < i.0 {
-            let dataset = Dataset::default()
-                .name(working_satellites.satellite.get_name())
-                .marker(Marker::Braille)
-                .graph_type(GraphType::Line)
-                .cyan()
-                .data(&points[current_start..current_end]);
-            paths_list.push(dataset);
-            current_start = current_end + 1;
         }
-        // debug!("current x: {}, last x: {:?}", i.0, prev);
-        current_end += 1;
-        prev = Some(i.0);
     }
-    let dataset = Dataset::default()
-        .name(working_satellites.satellite.get_name())
-        .marker(Marker::Braille)
-        .graph_type(GraphType::Line)
-        .data(&points[current_start..current_end])
-        .cyan();
-    paths_list.push(dataset);
-    paths_list.reverse();
-    let x_axis = Axis::default().bounds([-180.0, 180.0]);
-    let y_axis = Axis::default().bounds([-90.0, 90.0]);
+    let x_axis = Axis::default().bounds(projection.x_bounds());
+    let y_axis = Axis::default().bounds(projection.y_bounds());
     frame.render_widget(
         Chart::new(paths_list)
             .x_axis(x_axis)
@@ -133,51 +185,217 @@ fn render_tracks(model: &Model, frame: &mut Frame<'_>, draw_area: Rect) {
             .legend_position(None),
         draw_area,
     );
-    for i in model
+    let horizon = current_time + model.sub_point_range;
+    frame.render_widget(
+        map_canvas(projection, |ctx| {
+            for i in track_cache
+                .ticks()
+                .iter()
+                .filter(|x| x.time > current_time && x.time <= horizon)
+            {
+                let (x, y) = projection.project(i.lat, i.long);
+                let time = DateTime::from_timestamp(i.time, 0).unwrap_or_default();
+                ctx.print(
+                    x,
+                    y,
+                    format!("·{}", time.format("%H:%M")).fg(model.theme.track_future),
+                );
+            }
+            for i in track_cache.nodes() {
+                let (x, y) = projection.project(i.lat, i.long);
+                let label = match i.revolution {
+                    Some(rev) => format!("▲{}", rev),
+                    None => "▲".to_string(),
+                };
+                ctx.print(x, y, label.fg(model.theme.node));
+            }
+        }),
+        draw_area,
+    );
+    let active_stations = model
         .station_config
         .station_list
         .iter()
-        .filter(|x| x.active)
-    {
+        .filter(|x| x.active);
+    if model.map.show_station_rings {
+        let rings: Vec<Vec<(f64, f64)>> = active_stations
+            .clone()
+            .flat_map(|x| {
+                projection.project_path(&small_circle(
+                    x.station.lat,
+                    x.station.long,
+                    footprint_radius(current_pos.alt, x.elevation_mask),
+                    CIRCLE_SAMPLES,
+                ))
+            })
+            .collect();
         frame.render_widget(
-            Canvas::default()
-                .paint(|ctx| {
-                    ctx.print(
-                        i.station.long,
-                        i.station.lat,
-                        "+".yellow().into_centered_line(),
-                    )
-                })
-                .x_bounds([-180.0, 180.0])
-                .y_bounds([-90.0, 90.0]),
+            map_canvas(projection, |ctx| {
+                draw_segments(ctx, &rings, model.theme.station)
+            }),
             draw_area,
         );
     }
     frame.render_widget(
-        Canvas::default()
-            .paint(|ctx| {
+        map_canvas(projection, |ctx| {
+            for i in active_stations.clone() {
+                let (x, y) = projection.project(i.station.lat, i.station.long);
+                ctx.print(x, y, "+".fg(model.theme.station).into_centered_line())
+            }
+        }),
+        draw_area,
+    );
+    if model.map.show_footprint {
+        let footprint = projection.project_path(&small_circle(
+            current_pos.lat,
+            current_pos.long,
+            footprint_radius(current_pos.alt, model.map.footprint_elevation),
+            CIRCLE_SAMPLES,
+        ));
+        frame.render_widget(
+            map_canvas(projection, |ctx| {
+                draw_segments(ctx, &footprint, model.theme.satellite)
+            }),
+            draw_area,
+        );
+    }
+    frame.render_widget(
+        map_canvas(projection, |ctx| {
+            let (x, y) = projection.project(current_pos.lat, current_pos.long);
+            ctx.print(x, y, "#".fg(model.theme.satellite).into_centered_line());
+            debug!(?current_pos);
+        }),
+        draw_area,
+    );
+}
+
+fn draw_segments(ctx: &mut Context<'_>, segments: &[Vec<(f64, f64)>], color: Color) {
+    for segment in segments {
+        for pair in segment.windows(2) {
+            ctx.draw(&Line::new(
+                pair[0].0, pair[0].1, pair[1].0, pair[1].1, color,
+            ));
+        }
+    }
+}
+
+fn render_daylight(
+    model: &Model,
+    projection: &MapProjection,
+    frame: &mut Frame<'_>,
+    draw_area: Rect,
+) {
+    if !model.map.show_terminator {
+        return;
+    }
+    let now = model.clock.now();
+    let (sun_lat, sun_long) = sub_point(&sun_position(&now), &now);
+    let mut layers = vec![(
+        projection.project_path(&small_circle(sun_lat, sun_long, 90.0, CIRCLE_SAMPLES)),
+        model.theme.sun,
+    )];
+    if model.map.show_twilight {
+        let colors = [
+            model.theme.twilight_civil,
+            model.theme.twilight_nautical,
+            model.theme.twilight_astronomical,
+        ];
+        for (depression, color) in TWILIGHT_DEPRESSIONS.into_iter().zip(colors) {
+            layers.push((
+                projection.project_path(&small_circle(
+                    sun_lat,
+                    sun_long,
+                    90.0 + depression,
+                    CIRCLE_SAMPLES,
+                )),
+                color,
+            ));
+        }
+    }
+    frame.render_widget(
+        map_canvas(projection, |ctx| {
+            for (segments, color) in &layers {
+                draw_segments(ctx, segments, *color);
+            }
+        }),
+        draw_area,
+    );
+}
+
+/// Plots the current positions of the other members of the tracked group
+fn render_group(model: &Model, projection: &MapProjection, frame: &mut Frame<'_>, draw_area: Rect) {
+    let Some(group) = &model.tracked_group else {
+        return;
+    };
+    let satellites = &model.sat_config.satellite_list;
+    let current = model
+        .current_satellite
+        .as_ref()
+        .map(|x| x.satellite.get_norad_id());
+    let now = model.clock.now().timestamp();
+    frame.render_widget(
+        map_canvas(projection, |ctx| {
+            for i in model.sat_config.groups.tags.members(group, satellites) {
+                let satellite = &satellites[i].satellite;
+                if Some(satellite.get_norad_id()) == current {
+                    continue;
+                }
+                let position = satellite.get_sub_point(now - satellite.get_epoch().timestamp());
+                let (x, y) = projection.project(position.lat, position.long);
                 ctx.print(
-                    current_pos.long,
-                    current_pos.lat,
-                    "#".red().into_centered_line(),
+                    x,
+                    y,
+                    format!("•{}", satellite.get_name()).fg(model.theme.group),
                 );
-                debug!(?current_pos);
-            })
-            .x_bounds([-180.0, 180.0])
-            .y_bounds([-90.0, 90.0]),
+            }
+        }),
         draw_area,
     );
 }
 
-fn render_background_map(frame: &mut Frame<'_>, draw_area: Rect) {
-    let base_map = Canvas::default()
-        .paint(|ctx| {
-            ctx.draw(&Map {
-                color: Color::Green,
-                resolution: MapResolution::High,
-            });
-        })
-        .x_bounds([-180.0, 180.0])
-        .y_bounds([-90.0, 90.0]);
+/// Plots the sub-points of the Sun and Moon, labelling the one being tracked
+fn render_bodies(
+    model: &Model,
+    projection: &MapProjection,
+    frame: &mut Frame<'_>,
+    draw_area: Rect,
+) {
+    let now = model.clock.now();
+    frame.render_widget(
+        map_canvas(projection, |ctx| {
+            for body in CELESTIAL_BODIES {
+                let (lat, long) = sub_point(&body.position(&now), &now);
+                let (x, y) = projection.project(lat, long);
+                let color = match body {
+                    CelestialBody::Sun => model.theme.sun,
+                    CelestialBody::Moon => model.theme.moon,
+                };
+                if model.target == Some(body) {
+                    ctx.print(
+                        x,
+                        y,
+                        format!("{}{}", body.symbol(), body.name()).fg(color).bold(),
+                    );
+                } else {
+                    ctx.print(x, y, body.symbol().fg(color).into_centered_line());
+                }
+            }
+        }),
+        draw_area,
+    );
+}
+
+fn render_background_map(
+    model: &Model,
+    projection: &MapProjection,
+    frame: &mut Frame<'_>,
+    draw_area: Rect,
+) {
+    let base_map = map_canvas(projection, |ctx| {
+        ctx.draw(&Points {
+            coords: model.coastlines.points(),
+            color: model.theme.map,
+        });
+    });
     frame.render_widget(base_map, draw_area);
 }